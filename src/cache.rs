@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+use std::hash::Hash;
 use std::io::Write;
 use std::sync::Arc;
 
@@ -7,20 +9,113 @@ use arc_swap::ArcSwap;
 use bytes::Bytes;
 use flate2::Compression;
 use flate2::write::GzEncoder;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 use crate::config;
-use crate::providers::github::GhTrending;
+use crate::providers::advisories::AdvisoryFeed;
+use crate::providers::arxiv::ArxivFeed;
+use crate::providers::bluesky::BlueskyFeed;
+use crate::providers::command::CommandFeed;
+use crate::providers::crates::CratesFeed;
+use crate::providers::discourse::DiscourseFeed;
+use crate::providers::feeds::Feeds;
+use crate::providers::forgejo::ForgeRepos;
+use crate::providers::github::{GhDevelopers, GhTrending};
 use crate::providers::hackernews::HnPages;
+use crate::providers::hiring::HiringFeed;
+use crate::providers::huggingface::HfTrending;
+use crate::providers::ingest::IngestFeed;
+use crate::providers::lemmy::LemmyFeed;
+use crate::providers::mastodon::MastodonFeed;
+use crate::providers::producthunt::ProductHuntFeed;
 use crate::providers::reddit::RedditFeed;
+use crate::providers::releases::GhReleases;
+use crate::providers::stackexchange::StackExchangeFeed;
+use crate::providers::status::StatusFeed;
+use crate::providers::tildes::TildesFeed;
 use crate::render;
 
+/// Also the body of `/api/data`, which mirrors pull instead of scraping.
+#[derive(Serialize, Deserialize)]
 pub struct DataSnapshot {
     pub hn_pages: HnPages,
+    pub status_feed: StatusFeed,
+    #[serde(with = "tuple_keys")]
+    pub hiring_feed: HiringFeed,
+    #[serde(with = "tuple_keys")]
     pub gh_trending: GhTrending,
+    #[serde(with = "tuple_keys")]
+    pub gh_developers: GhDevelopers,
+    pub gh_releases: GhReleases,
+    #[serde(with = "tuple_keys")]
+    pub forge_repos: ForgeRepos,
+    #[serde(with = "tuple_keys")]
     pub reddit_feed: RedditFeed,
+    pub lemmy_feed: LemmyFeed,
+    pub tildes_feed: TildesFeed,
+    pub mastodon_feed: MastodonFeed,
+    pub bluesky_feed: BlueskyFeed,
+    #[serde(with = "tuple_keys")]
+    pub stackexchange_feed: StackExchangeFeed,
+    #[serde(with = "tuple_keys")]
+    pub discourse_feed: DiscourseFeed,
+    pub advisory_feed: AdvisoryFeed,
+    pub arxiv_feed: ArxivFeed,
+    pub hf_trending: HfTrending,
+    #[serde(with = "tuple_keys")]
+    pub producthunt_feed: ProductHuntFeed,
+    pub crates_feed: CratesFeed,
+    #[serde(with = "tuple_keys")]
+    pub feeds: Feeds,
+    pub command_feed: CommandFeed,
+    /// A handle rather than a map: pushes land between scrapes, and the scraper
+    /// carries the handle over instead of a copy that would drop them.
+    #[serde(with = "shared_ingest")]
+    pub ingest_feed: SharedIngest,
     pub last_fetched: DateTime<Utc>,
 }
 
+/// JSON object keys must be strings, so maps keyed by tuples are sent as
+/// `[[key, value], ...]`.
+mod tuple_keys {
+    use super::*;
+
+    pub fn serialize<K, V, S>(map: &HashMap<K, V>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        K: Serialize,
+        V: Serialize,
+        S: Serializer,
+    {
+        serializer.collect_seq(map)
+    }
+
+    pub fn deserialize<'de, K, V, D>(deserializer: D) -> Result<HashMap<K, V>, D::Error>
+    where
+        K: Deserialize<'de> + Eq + Hash,
+        V: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        let pairs = Vec::<(K, V)>::deserialize(deserializer)?;
+        Ok(pairs.into_iter().collect())
+    }
+}
+
+/// The pushed items as of serialization; a mirror gets a handle of its own.
+mod shared_ingest {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(feed: &SharedIngest, serializer: S) -> Result<S::Ok, S::Error> {
+        feed.load().serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<SharedIngest, D::Error> {
+        let feed = IngestFeed::deserialize(deserializer)?;
+        Ok(Arc::new(ArcSwap::from_pointee(feed)))
+    }
+}
+
 /// Pre-rendered + compressed HTML. `Bytes` fields are cheap (refcount) clones on each request.
 pub struct HtmlSnapshot {
     pub html: Bytes,
@@ -33,12 +128,7 @@ pub struct HtmlSnapshot {
 
 impl HtmlSnapshot {
     pub fn from_data(data: &DataSnapshot) -> Option<Self> {
-        let html = render::render_page(
-            &data.hn_pages,
-            &data.gh_trending,
-            &data.reddit_feed,
-            data.last_fetched,
-        );
+        let html = render::render_page(data);
         Self::compress(html, config::HTML_REFRESH_SECS, false)
     }
 
@@ -72,7 +162,7 @@ const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
 const FNV_PRIME: u64 = 0x100000001b3;
 
 /// FNV-1a hash — deterministic across restarts (unlike DefaultHasher).
-fn compute_etag(data: &[u8]) -> String {
+pub fn compute_etag(data: &[u8]) -> String {
     let mut hash = FNV_OFFSET_BASIS;
     for &byte in data {
         hash ^= byte as u64;
@@ -94,6 +184,7 @@ fn compress_zstd(data: &[u8]) -> std::io::Result<Vec<u8>> {
 /// ArcSwap gives lock-free reads under concurrent requests (vs RwLock which blocks readers during writes).
 pub type SharedData = Arc<ArcSwap<DataSnapshot>>;
 pub type SharedHtml = Arc<ArcSwap<HtmlSnapshot>>;
+pub type SharedIngest = Arc<ArcSwap<IngestFeed>>;
 
 #[derive(Clone)]
 pub struct AppState {
@@ -104,8 +195,27 @@ pub struct AppState {
 pub fn new_shared_data() -> SharedData {
     Arc::new(ArcSwap::new(Arc::new(DataSnapshot {
         hn_pages: HnPages::new(),
+        status_feed: StatusFeed::new(),
+        hiring_feed: HiringFeed::new(),
         gh_trending: GhTrending::new(),
+        gh_developers: GhDevelopers::new(),
+        gh_releases: GhReleases::new(),
+        forge_repos: ForgeRepos::new(),
         reddit_feed: RedditFeed::new(),
+        lemmy_feed: LemmyFeed::new(),
+        tildes_feed: TildesFeed::new(),
+        mastodon_feed: MastodonFeed::new(),
+        bluesky_feed: BlueskyFeed::new(),
+        stackexchange_feed: StackExchangeFeed::new(),
+        discourse_feed: DiscourseFeed::new(),
+        advisory_feed: AdvisoryFeed::new(),
+        arxiv_feed: ArxivFeed::new(),
+        hf_trending: HfTrending::new(),
+        producthunt_feed: ProductHuntFeed::new(),
+        crates_feed: CratesFeed::new(),
+        feeds: Feeds::new(),
+        command_feed: CommandFeed::new(),
+        ingest_feed: Arc::new(ArcSwap::from_pointee(IngestFeed::new())),
         last_fetched: DateTime::UNIX_EPOCH,
     })))
 }
@@ -113,3 +223,310 @@ pub fn new_shared_data() -> SharedData {
 pub fn new_shared_html() -> SharedHtml {
     Arc::new(ArcSwap::new(Arc::new(HtmlSnapshot::loading())))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::providers::advisories::Advisory;
+    use crate::providers::arxiv::ArxivPaper;
+    use crate::providers::bluesky::BlueskyPost;
+    use crate::providers::command::{CommandPanel, CommandStatus, ExternalItem};
+    use crate::providers::crates::CrateInfo;
+    use crate::providers::discourse::DiscourseTopic;
+    use crate::providers::feeds::FeedItem;
+    use crate::providers::github::{PopularRepo, TrendingDeveloper, TrendingRepo};
+    use crate::providers::hackernews::HnStory;
+    use crate::providers::hiring::{Job, Workplace};
+    use crate::providers::huggingface::HfRepo;
+    use crate::providers::ingest::IngestedItem;
+    use crate::providers::lemmy::LemmyPost;
+    use crate::providers::mastodon::MastodonPost;
+    use crate::providers::producthunt::ProductLaunch;
+    use crate::providers::reddit::{RedditPost, RedditSource};
+    use crate::providers::releases::GhRelease;
+    use crate::providers::stackexchange::StackQuestion;
+    use crate::providers::status::{Indicator, ServiceStatus, StatusEvent};
+    use crate::providers::tildes::TildesTopic;
+
+    const NOW: i64 = 1792314000;
+
+    fn at(secs: i64) -> DateTime<Utc> {
+        DateTime::from_timestamp(secs, 0).unwrap()
+    }
+
+    fn key(a: &str, b: &str) -> (String, String) {
+        (a.to_string(), b.to_string())
+    }
+
+    fn trending_repo() -> TrendingRepo {
+        TrendingRepo {
+            author: "ferris".into(),
+            name: "claws".into(),
+            url: "https://github.com/ferris/claws".into(),
+            description: "Pinch-safe allocators".into(),
+            language: Some("Rust".into()),
+            language_color: Some("#dea584".into()),
+            stars: 1234,
+            forks: 56,
+            period_stars: "78 stars today".into(),
+            contributors: vec!["ferris".into(), "corro".into()],
+        }
+    }
+
+    fn external_item() -> ExternalItem {
+        ExternalItem {
+            id: Some("api".into()),
+            title: "api: deployed v41".into(),
+            url: Some("https://ci.example.com/api/41".into()),
+            author: Some("deploy-bot".into()),
+            score: Some(3),
+            timestamp: Some(NOW as u64),
+        }
+    }
+
+    /// One entry in every map, with every optional field set, so a field the
+    /// mirror format drops or renames shows up as a difference.
+    fn populated_snapshot() -> DataSnapshot {
+        let hn_story = HnStory {
+            id: 42,
+            title: "Show HN: tty1".into(),
+            url: Some("https://example.com/".into()),
+            hn_url: "https://news.ycombinator.com/item?id=42".into(),
+            score: 120,
+            author: "pg".into(),
+            created_at: NOW as u64,
+            comment_count: 31,
+            domain: Some("example.com".into()),
+        };
+        let event = StatusEvent {
+            name: "Degraded API latency".into(),
+            url: "https://status.example.com/incidents/1".into(),
+            status: "investigating".into(),
+            at: at(NOW),
+        };
+        let service = ServiceStatus {
+            name: "GitHub".into(),
+            url: "https://www.githubstatus.com".into(),
+            indicator: Indicator::Minor,
+            description: "Minor Service Outage".into(),
+            degraded_components: vec!["Actions".into()],
+            incidents: vec![event.clone()],
+            maintenances: vec![event],
+        };
+        let job = Job {
+            company: "Ferrous".into(),
+            role: Some("Backend engineer".into()),
+            location: Some("Berlin".into()),
+            workplace: Workplace::Hybrid,
+            stack: Some("Rust".into()),
+            summary: "Compilers.".into(),
+            url: "https://news.ycombinator.com/item?id=43".into(),
+            posted: NOW as u64,
+            regions: vec!["Europe"],
+            tech: vec!["Rust"],
+        };
+        let developer = TrendingDeveloper {
+            login: "ferris".into(),
+            name: "Ferris".into(),
+            url: "https://github.com/ferris".into(),
+            popular_repo: Some(PopularRepo {
+                name: "claws".into(),
+                url: "https://github.com/ferris/claws".into(),
+                description: "Pinch-safe allocators".into(),
+            }),
+        };
+        let release = GhRelease {
+            repo: "tokio-rs/tokio".into(),
+            tag: "tokio-2.0.0".into(),
+            name: "Tokio v2.0.0".into(),
+            url: "https://github.com/tokio-rs/tokio/releases/tag/tokio-2.0.0".into(),
+            prerelease: true,
+            published: at(NOW),
+            major_bump: true,
+        };
+        let reddit_post = RedditPost {
+            title: "Rust 2.0 is not happening".into(),
+            url: "https://blog.rust-lang.org/".into(),
+            permalink: "https://old.reddit.com/r/rust/comments/abc/".into(),
+            subreddit: "rust".into(),
+            score: 900,
+            author: "ferris".into(),
+            created_at: NOW as f64,
+            comment_count: 120,
+            domain: "blog.rust-lang.org".into(),
+            is_self: false,
+            source: RedditSource::Rss,
+        };
+        let lemmy_post = LemmyPost {
+            title: "Zig 0.16 released".into(),
+            url: "https://ziglang.org/".into(),
+            post_url: "https://programming.dev/post/1".into(),
+            community: "zig@programming.dev".into(),
+            score: 40,
+            comment_count: 7,
+            author: "andrew".into(),
+            created_at: at(NOW),
+            domain: "ziglang.org".into(),
+            is_self: false,
+        };
+        let tildes_topic = TildesTopic {
+            title: "Favourite terminal emulators?".into(),
+            url: "https://tildes.net/~comp/1".into(),
+            topic_url: "https://tildes.net/~comp/1".into(),
+            group: "~comp".into(),
+            tags: vec!["ask".into()],
+            votes: 12,
+            comment_count: 30,
+            author: "deimos".into(),
+            posted_at: at(NOW),
+        };
+        let mastodon_post = MastodonPost {
+            uri: "https://hachyderm.io/users/ferris/statuses/1".into(),
+            url: "https://hachyderm.io/@ferris/1".into(),
+            author: "ferris@hachyderm.io".into(),
+            text: "New crate out".into(),
+            created_at: at(NOW),
+            boosts: 4,
+            favourites: 9,
+            replies: 2,
+        };
+        let bluesky_post = BlueskyPost {
+            uri: "at://did:plc:ferris/app.bsky.feed.post/1".into(),
+            url: "https://bsky.app/profile/ferris.dev/post/1".into(),
+            handle: "ferris.dev".into(),
+            text: "New crate out".into(),
+            created_at: at(NOW),
+            likes: 20,
+            reposts: 3,
+            replies: 1,
+        };
+        let question = StackQuestion {
+            title: "Why does the borrow checker reject this?".into(),
+            link: "https://stackoverflow.com/q/1".into(),
+            score: 5,
+            answer_count: 2,
+            accepted_answer_id: Some(2),
+            tags: vec!["rust".into()],
+            creation_date: at(NOW),
+            site: "Stack Overflow".into(),
+        };
+        let topic = DiscourseTopic {
+            id: 7,
+            title: "Pre-RFC: async drop".into(),
+            url: "https://internals.rust-lang.org/t/7".into(),
+            forum: "Rust Internals".into(),
+            replies: 14,
+            likes: 30,
+            views: 900,
+            last_activity: at(NOW),
+        };
+        let advisory: Advisory = serde_json::from_value(serde_json::json!({
+            "ghsa_id": "GHSA-xxxx-yyyy-zzzz", "cve_id": "CVE-2026-0001",
+            "html_url": "https://github.com/advisories/GHSA-xxxx-yyyy-zzzz",
+            "summary": "Out-of-bounds read", "severity": "high",
+            "published_at": "2026-10-18T09:00:00Z",
+            "vulnerabilities": [{ "package": { "name": "claws" } }],
+            "ecosystem": "crates.io"
+        }))
+        .unwrap();
+        let paper = ArxivPaper {
+            id: "2610.00001".into(),
+            title: "Ownership types, revisited".into(),
+            authors: vec!["A. Crab".into()],
+            author_count: 3,
+            primary_category: "cs.PL".into(),
+            summary: "We revisit ownership.".into(),
+            abs_url: "https://arxiv.org/abs/2610.00001".into(),
+            pdf_url: "https://arxiv.org/pdf/2610.00001".into(),
+            published: at(NOW),
+        };
+        let hf_repo = HfRepo {
+            id: "crab-lab/Crab-7B".into(),
+            url: "https://huggingface.co/crab-lab/Crab-7B".into(),
+            likes: 812,
+            downloads: Some(120345),
+            task: Some("text-generation".into()),
+            last_modified: Some(at(NOW)),
+        };
+        let launch = ProductLaunch {
+            id: "1".into(),
+            name: "Claws".into(),
+            tagline: "Allocators for crabs".into(),
+            url: "https://www.producthunt.com/posts/claws".into(),
+            votes: 300,
+            comment_count: 12,
+            created_at: at(NOW),
+        };
+        let krate = CrateInfo {
+            name: "tokio".into(),
+            version: "1.52.3".into(),
+            description: Some("An event-driven, non-blocking I/O platform".into()),
+            downloads: 500_000_000,
+            recent_downloads: Some(60_000_000),
+            updated_at: at(NOW),
+        };
+        let feed_item = FeedItem {
+            guid: "https://blog.rust-lang.org/1".into(),
+            title: "Announcing Rust 1.95".into(),
+            link: "https://blog.rust-lang.org/1".into(),
+            author: Some("The Release Team".into()),
+            published: NOW as u64,
+            feed: "Rust Blog".into(),
+        };
+        let command_panel = CommandPanel {
+            items: vec![external_item()],
+            status: CommandStatus {
+                ok: false,
+                message: "exit status 1".into(),
+                stderr: "warning: stale cache".into(),
+                ran_at: at(NOW),
+            },
+        };
+        let ingested = IngestedItem {
+            item: external_item(),
+            expires_at: NOW as u64 + 3600,
+        };
+
+        DataSnapshot {
+            hn_pages: HashMap::from([("top".into(), vec![hn_story])]),
+            status_feed: vec![service],
+            hiring_feed: HashMap::from([((43, "Ask HN: Who is hiring?".into()), vec![job])]),
+            gh_trending: HashMap::from([(key("daily", "Rust"), vec![trending_repo()])]),
+            gh_developers: HashMap::from([(key("daily", "all"), vec![developer])]),
+            gh_releases: HashMap::from([("tokio-rs/tokio".into(), vec![release])]),
+            forge_repos: HashMap::from([(key("stars", "codeberg"), vec![trending_repo()])]),
+            reddit_feed: HashMap::from([(key("rust", "hot"), vec![reddit_post])]),
+            lemmy_feed: HashMap::from([("zig@programming.dev".into(), vec![lemmy_post])]),
+            tildes_feed: HashMap::from([("~comp".into(), vec![tildes_topic])]),
+            mastodon_feed: HashMap::from([("ferris".into(), vec![mastodon_post])]),
+            bluesky_feed: HashMap::from([("ferris.dev".into(), vec![bluesky_post])]),
+            stackexchange_feed: HashMap::from([(key("Stack Overflow", "hot"), vec![question])]),
+            discourse_feed: HashMap::from([(key("Rust Internals", "latest"), vec![topic])]),
+            advisory_feed: HashMap::from([("crates.io".into(), vec![advisory])]),
+            arxiv_feed: HashMap::from([("cs.PL".into(), vec![paper])]),
+            hf_trending: HashMap::from([("models".into(), vec![hf_repo])]),
+            producthunt_feed: HashMap::from([(key("today", "all"), vec![launch])]),
+            crates_feed: HashMap::from([("watchlist".into(), vec![krate])]),
+            feeds: HashMap::from([(key("blogs", "Rust Blog"), vec![feed_item])]),
+            command_feed: HashMap::from([("deploys".into(), command_panel)]),
+            ingest_feed: Arc::new(ArcSwap::from_pointee(HashMap::from([(
+                "deploys".into(),
+                vec![ingested],
+            )]))),
+            last_fetched: at(NOW),
+        }
+    }
+
+    #[test]
+    fn populated_snapshot_round_trips() {
+        let json = serde_json::to_value(populated_snapshot()).unwrap();
+        for (field, value) in json.as_object().unwrap() {
+            let empty = value.as_array().is_some_and(Vec::is_empty)
+                || value.as_object().is_some_and(|m| m.is_empty());
+            assert!(!empty, "{field} is empty in the fixture");
+        }
+
+        let back: DataSnapshot = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(serde_json::to_value(&back).unwrap(), json);
+    }
+}
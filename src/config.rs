@@ -20,25 +20,171 @@ pub const REQUEST_TIMEOUT_SECS: u64 = 10;
 pub const GZIP_LEVEL: u32 = 6;
 pub const ZSTD_LEVEL: i32 = 6;
 
+// -- Mirror --
+/// Between pulls of the upstream's snapshot; an unchanged one is a bare 304.
+pub const MIRROR_INTERVAL_SECS: u64 = 60;
+
+/// Base URL of another instance, e.g. "https://tty1.example.com". When set,
+/// this instance serves that one's data instead of running any provider.
+pub fn mirror_upstream() -> Option<String> {
+    std::env::var("MIRROR_UPSTREAM")
+        .ok()
+        .filter(|s| !s.is_empty())
+}
+
+/// Shared by an upstream and its mirrors; `/api/data` is disabled without it.
+pub fn mirror_token() -> Option<String> {
+    std::env::var("MIRROR_TOKEN").ok().filter(|s| !s.is_empty())
+}
+
 // -- Hacker News --
 pub const HN_API_BASE: &str = "https://hacker-news.firebaseio.com/v0";
+pub const HN_ALGOLIA_BASE: &str = "https://hn.algolia.com/api/v1";
 pub const HN_STORIES_PER_PAGE: usize = 30;
 pub const HN_CONCURRENT_FETCHES: usize = 10;
-pub const HN_PAGES: &[(&str, &str)] = &[
-    ("top", "topstories"),
-    ("newest", "newstories"),
-    ("show", "showstories"),
+
+/// Where an HN list comes from.
+pub enum HnSource {
+    /// Firebase story-id endpoint, e.g. "topstories".
+    Firebase(&'static str),
+    /// Algolia search: stories that made the front page, with more than
+    /// `min_points`, submitted within the last `window_secs`. Stories that
+    /// cleared the threshold without reaching the front page are left out.
+    Algolia { min_points: u32, window_secs: u64 },
+}
+
+pub const HN_PAGES: &[(&str, HnSource)] = &[
+    ("top", HnSource::Firebase("topstories")),
+    ("newest", HnSource::Firebase("newstories")),
+    ("show", HnSource::Firebase("showstories")),
+    ("best", HnSource::Firebase("beststories")),
+    ("ask", HnSource::Firebase("askstories")),
+    ("jobs", HnSource::Firebase("jobstories")),
+    (
+        "24h",
+        HnSource::Algolia {
+            min_points: 100,
+            window_secs: 86400,
+        },
+    ),
+    (
+        "7d",
+        HnSource::Algolia {
+            min_points: 300,
+            window_secs: 7 * 86400,
+        },
+    ),
+];
+
+// -- HN "Who is hiring?" --
+pub const HIRING_USER: &str = "whoishiring";
+pub const HIRING_THREAD_PREFIX: &str = "Ask HN: Who is hiring?";
+/// The account posts three threads a month; this covers the latest batch plus slack.
+pub const HIRING_SUBMISSIONS_SCANNED: usize = 6;
+/// Top-level comments fetched; a busy month has ~400.
+pub const HIRING_MAX_COMMENTS: usize = 500;
+/// Header parts this short that name a workplace ("REMOTE", "Onsite or Remote")
+/// are markers only; longer ones ("Remote (US time zones)") also fill location.
+pub const HIRING_MARKER_MAX_LEN: usize = 24;
+pub const HIRING_SUMMARY_CHARS: usize = 280;
+/// Location filter: label → whole-word patterns matched against the header line.
+pub const HIRING_LOCATIONS: &[(&str, &[&str])] = &[
+    (
+        "US",
+        &[
+            "us",
+            "usa",
+            "united states",
+            "sf",
+            "san francisco",
+            "bay area",
+            "nyc",
+            "new york",
+            "seattle",
+            "boston",
+            "austin",
+            "chicago",
+            "los angeles",
+            "denver",
+        ],
+    ),
+    ("Canada", &["canada", "toronto", "vancouver", "montreal"]),
+    (
+        "UK",
+        &["uk", "united kingdom", "london", "manchester", "edinburgh"],
+    ),
+    (
+        "Europe",
+        &[
+            "eu",
+            "europe",
+            "emea",
+            "germany",
+            "berlin",
+            "munich",
+            "amsterdam",
+            "netherlands",
+            "paris",
+            "france",
+            "spain",
+            "barcelona",
+            "madrid",
+            "stockholm",
+            "zurich",
+            "dublin",
+            "lisbon",
+            "warsaw",
+        ],
+    ),
+    (
+        "Asia",
+        &[
+            "asia",
+            "apac",
+            "singapore",
+            "tokyo",
+            "japan",
+            "india",
+            "bangalore",
+        ],
+    ),
+    ("LatAm", &["latam", "brazil", "mexico", "argentina"]),
+];
+/// Technology filter: label → whole-word patterns matched anywhere in the post.
+/// Bare "go" is too common in prose to mean the language.
+pub const HIRING_TECH: &[(&str, &[&str])] = &[
+    ("Rust", &["rust"]),
+    ("Go", &["golang"]),
+    ("Python", &["python", "django", "fastapi"]),
+    ("TypeScript", &["typescript", "ts"]),
+    ("JavaScript", &["javascript", "node", "nodejs", "react"]),
+    ("C++", &["c++", "cpp"]),
+    ("Java", &["java", "kotlin"]),
+    ("Elixir", &["elixir", "erlang"]),
+    ("Ruby", &["ruby", "rails"]),
+    ("Kubernetes", &["kubernetes", "k8s"]),
+    ("ML", &["ml", "machine learning", "llm", "pytorch"]),
 ];
 
 // -- GitHub --
 pub const GITHUB_TRENDING_URL: &str = "https://github.com/trending";
 /// GitHub applies secondary limits to bursty unauthenticated scraping; pace requests.
-/// More tolerant than Reddit, so faster (2/s) — still finishes under the Reddit leg.
+/// More tolerant than Reddit, so faster (2/s).
+/// One schedule covers trending, releases and advisories together.
 pub const GITHUB_REQUEST_INTERVAL_MS: u64 = 500;
 /// Randomize each gap by ±this so the cadence is not a fixed (bot-detectable) interval.
 pub const GITHUB_REQUEST_JITTER_MS: u64 = 150;
 pub const GITHUB_REPOS_PER_PAGE: usize = 25;
+/// `spoken_language_code` for the repo trending pages (ISO 639-1, e.g. `Some("en")`).
+/// GitHub infers it from the README; `None` shows every language.
+/// The developers page ignores it.
+pub const GITHUB_SPOKEN_LANGUAGE: Option<&str> = None;
+/// "Built by" avatars shown per trending repo; GitHub lists at most five.
+pub const GITHUB_CONTRIBUTORS_SHOWN: usize = 5;
 pub const GITHUB_PERIODS: &[&str] = &["daily", "weekly", "monthly"];
+/// Views toggled in the GitHub panel: `/trending`, `/trending/developers`, and
+/// the latest releases of `GITHUB_RELEASE_REPOS`.
+pub const GITHUB_TRENDING_VIEWS: &[&str] = &["repos", "developers", "releases"];
 pub const GITHUB_LANGUAGES: &[(&str, &str)] = &[
     ("Rust", "rust"),
     ("Go", "go"),
@@ -59,6 +205,83 @@ pub const GITHUB_LANGUAGES: &[(&str, &str)] = &[
     ("Elixir", "elixir"),
 ];
 
+// -- GitHub releases --
+pub const GITHUB_API_BASE: &str = "https://api.github.com";
+/// Repos ("owner/repo") whose releases are listed in the GitHub panel. Only
+/// repos that publish GitHub Releases: tag-only mirrors such as golang/go have
+/// nothing for the releases API to return.
+pub const GITHUB_RELEASE_REPOS: &[&str] = &[
+    "rust-lang/rust",
+    "rust-lang/rust-analyzer",
+    "tokio-rs/tokio",
+    "tokio-rs/axum",
+    "hyperium/hyper",
+    "seanmonstar/reqwest",
+    "serde-rs/serde",
+    "dtolnay/anyhow",
+    "clap-rs/clap",
+    "BurntSushi/ripgrep",
+    "astral-sh/uv",
+    "astral-sh/ruff",
+    "NixOS/nixpkgs",
+    "nix-community/home-manager",
+    "neovim/neovim",
+    "helix-editor/helix",
+    "zed-industries/zed",
+    "nodejs/node",
+    "denoland/deno",
+    "oven-sh/bun",
+    "ziglang/zig",
+    "kubernetes/kubernetes",
+    "docker/compose",
+];
+pub const GITHUB_RELEASES_PER_REPO: usize = 5;
+pub const GITHUB_RELEASES_ALL_VIEW_LIMIT: usize = 60;
+/// Flag releases that bump the major version (the minor one for 0.x) over the
+/// repo's previous release.
+pub const GITHUB_RELEASES_HIGHLIGHT_MAJOR: bool = true;
+
+/// Optional token (`GITHUB_TOKEN`): releases come from the REST API instead
+/// of the Atom feeds, with GitHub's exact prerelease flag.
+pub fn github_token() -> Option<String> {
+    std::env::var("GITHUB_TOKEN").ok().filter(|s| !s.is_empty())
+}
+
+// -- Forgejo / Gitea --
+/// (label, instance base URL, owner). Without an owner the whole instance is
+/// searched (source repos only); with one, only that user's or org's repos.
+pub const FORGE_SOURCES: &[(&str, &str, Option<&str>)] = &[
+    ("codeberg", "https://codeberg.org", None),
+    ("forgejo", "https://codeberg.org", Some("forgejo")),
+    ("gitea", "https://gitea.com", Some("gitea")),
+];
+/// `/repos/search` sort orders, shown as tabs: most starred, recently pushed, newest.
+pub const FORGE_SORTS: &[&str] = &["stars", "updated", "created"];
+pub const FORGE_REPOS_PER_SOURCE: usize = 25;
+/// Codeberg is donation-funded; space requests like the other community instances.
+pub const FORGE_REQUEST_INTERVAL_MS: u64 = 500;
+pub const FORGE_REQUEST_JITTER_MS: u64 = 150;
+
+// -- Product Hunt --
+pub const PRODUCTHUNT_API_URL: &str = "https://api.producthunt.com/v2/api/graphql";
+/// (label, topic slug).
+pub const PRODUCTHUNT_TOPICS: &[(&str, &str)] = &[
+    ("developer tools", "developer-tools"),
+    ("open source", "open-source"),
+];
+/// (tab, window in hours). Product Hunt's day runs on Pacific time; rolling
+/// windows avoid pulling in a timezone database for it.
+pub const PRODUCTHUNT_TABS: &[(&str, i64)] = &[("today", 24), ("week", 7 * 24)];
+pub const PRODUCTHUNT_POSTS_PER_TOPIC: usize = 20;
+
+/// Developer token (`PRODUCTHUNT_TOKEN`) from producthunt.com/v2/oauth/applications.
+/// The API has no anonymous access, so the provider is skipped without one.
+pub fn producthunt_token() -> Option<String> {
+    std::env::var("PRODUCTHUNT_TOKEN")
+        .ok()
+        .filter(|s| !s.is_empty())
+}
+
 // -- Reddit --
 pub const REDDIT_SUBREDDITS: &[&str] = &[
     "rust",
@@ -104,4 +327,350 @@ pub const REDDIT_REQUEST_INTERVAL_MS: u64 = 1000;
 /// Randomize each gap by ±this so the cadence is not a fixed (bot-detectable) interval.
 pub const REDDIT_REQUEST_JITTER_MS: u64 = 300;
 pub const REDDIT_POSTS_PER_SUB: usize = 30;
+/// Posts requested per combined `/r/a+b+c/{sort}` listing; old.reddit caps
+/// `limit` at 100 and the parsers add a 5-row margin for filtered stickies and ads.
+pub const REDDIT_MULTI_LIMIT: usize = 95;
+/// Subreddits per combined listing. A combined listing is shared in proportion
+/// to activity, so batches only mix subreddits of similar activity: the busy
+/// ones in `REDDIT_BUSY_SUBREDDITS` with each other, the rest with each other.
+pub const REDDIT_MULTI_BATCH_SIZE: usize = 3;
+/// Subreddits that would crowd a quieter neighbour out of a combined listing.
+pub const REDDIT_BUSY_SUBREDDITS: &[&str] = &[
+    "programming",
+    "python",
+    "javascript",
+    "webdev",
+    "linux",
+    "selfhosted",
+    "homelab",
+    "ClaudeAI",
+    "OpenAI",
+    "artificial",
+    "claudecode",
+    "reactjs",
+];
+/// A subreddit is refetched alone only when a combined listing came back full
+/// (so it was cut off) and left it under this many posts.
+pub const REDDIT_REFETCH_BELOW: usize = REDDIT_POSTS_PER_SUB / 2;
+/// Listing sort modes: (key, old.reddit listing path, `t=` time window).
+pub const REDDIT_SORTS: &[(&str, &str, Option<&str>)] = &[
+    ("hot", "hot", None),
+    ("rising", "rising", None),
+    ("top-day", "top", Some("day")),
+    ("top-week", "top", Some("week")),
+];
+/// Sorts fetched for subreddits not listed in `REDDIT_SUB_SORTS`.
+pub const REDDIT_DEFAULT_SORTS: &[&str] = &["hot"];
+/// Per-subreddit sort overrides. Every sort is one more paced request per cycle, so
+/// only add them where they pay off: "top-week" for slow subs, "rising" for busy ones.
+pub const REDDIT_SUB_SORTS: &[(&str, &[&str])] = &[
+    ("rust", &["hot", "rising", "top-day"]),
+    ("programming", &["hot", "rising"]),
+    ("linux", &["hot", "rising"]),
+    ("zig", &["hot", "top-week"]),
+    ("NixOS", &["hot", "top-week"]),
+    ("elixir", &["hot", "top-week"]),
+    ("bun", &["hot", "top-week"]),
+];
 pub const REDDIT_ALL_VIEW_LIMIT: usize = 100;
+pub const REDDIT_OAUTH_TOKEN_URL: &str = "https://www.reddit.com/api/v1/access_token";
+pub const REDDIT_OAUTH_API_BASE: &str = "https://oauth.reddit.com";
+/// Reddit's API rules require `<platform>:<app id>:<version> (by ...)` on OAuth calls.
+pub const REDDIT_OAUTH_USER_AGENT: &str = concat!(
+    "web:tty1:v",
+    env!("CARGO_PKG_VERSION"),
+    " (by github.com/saanuregh/tty1)"
+);
+
+/// App-only OAuth credentials (`REDDIT_CLIENT_ID` + `REDDIT_CLIENT_SECRET`).
+/// When unset, the Reddit source chain starts at old.reddit HTML.
+pub fn reddit_oauth_credentials() -> Option<(String, String)> {
+    let id = std::env::var("REDDIT_CLIENT_ID")
+        .ok()
+        .filter(|s| !s.is_empty())?;
+    let secret = std::env::var("REDDIT_CLIENT_SECRET")
+        .ok()
+        .filter(|s| !s.is_empty())?;
+    Some((id, secret))
+}
+
+// -- crates.io --
+pub const CRATES_API_BASE: &str = "https://crates.io/api/v1";
+/// crates.io's crawler policy requires a User-Agent naming the app and a contact point.
+pub const CRATES_USER_AGENT: &str = "tty1 (https://github.com/saanuregh/tty1)";
+/// Crawler policy caps clients at 1 request/s. Jitter is kept below the 100ms
+/// surplus so the gap never drops under one second.
+pub const CRATES_REQUEST_INTERVAL_MS: u64 = 1100;
+pub const CRATES_REQUEST_JITTER_MS: u64 = 100;
+pub const CRATES_PER_TAB: usize = 25;
+pub const CRATES_TABS: &[&str] = &["new", "updated", "trending"];
+/// Always pinned to the top of every crates tab.
+pub const CRATES_WATCHLIST: &[&str] = &["tokio", "axum", "serde", "reqwest", "clap"];
+
+// -- Feeds (RSS/Atom) --
+/// Each entry is one dashboard panel: (panel name, [(feed name, feed URL)]).
+/// RSS 2.0, RSS 1.0 (RDF) and Atom are all accepted.
+pub const FEED_PANELS: &[(&str, &[(&str, &str)])] = &[
+    (
+        "news",
+        &[
+            ("LWN", "https://lwn.net/headlines/rss"),
+            ("Slashdot", "https://rss.slashdot.org/Slashdot/slashdotMain"),
+        ],
+    ),
+    (
+        "blogs",
+        &[
+            (
+                "This Week in Rust",
+                "https://this-week-in-rust.org/atom.xml",
+            ),
+            ("Cloudflare", "https://blog.cloudflare.com/rss/"),
+            ("Netflix", "https://netflixtechblog.com/feed"),
+        ],
+    ),
+];
+/// Feeds live on unrelated hosts, so they can be fetched in parallel.
+pub const FEED_CONCURRENT_FETCHES: usize = 4;
+pub const FEED_ITEMS_PER_FEED: usize = 30;
+pub const FEED_ALL_VIEW_LIMIT: usize = 100;
+
+// -- External commands --
+/// Each entry is one dashboard panel: (panel name, program, arguments). The
+/// program runs every scrape cycle and prints its items as JSON on stdout
+/// (schema in the README). No shell is involved; wrap pipelines in `sh -c`.
+pub const COMMAND_PANELS: &[(&str, &str, &[&str])] = &[];
+/// The child is killed once this elapses and the panel keeps its previous items.
+pub const COMMAND_TIMEOUT_SECS: u64 = 30;
+pub const COMMAND_ITEMS_LIMIT: usize = 100;
+/// Stderr kept for the panel's status line.
+pub const COMMAND_STDERR_CHARS: usize = 500;
+
+// -- Push ingest --
+/// Each entry is one dashboard panel fed by `POST /ingest/{panel}`: (panel name,
+/// env var holding its bearer token). Pushes to a panel whose variable is unset
+/// are rejected.
+pub const INGEST_PANELS: &[(&str, &str)] = &[];
+pub const INGEST_DEFAULT_TTL_SECS: u64 = 24 * 60 * 60;
+pub const INGEST_MAX_TTL_SECS: u64 = 7 * 24 * 60 * 60;
+/// Per panel; the oldest items are dropped first.
+pub const INGEST_ITEMS_LIMIT: usize = 100;
+
+pub fn ingest_token(token_var: &str) -> Option<String> {
+    std::env::var(token_var).ok().filter(|s| !s.is_empty())
+}
+
+// -- Mastodon --
+/// What to read from an instance.
+pub enum MastodonSource {
+    /// Public hashtag timeline, without the `#`.
+    Tag(&'static str),
+    /// Statuses (and boosts) of a local account, by username.
+    Account(&'static str),
+}
+
+/// (instance base URL, source). The same tag on two instances gives two views of
+/// the fediverse; posts seen by both are deduplicated in the merged view.
+pub const MASTODON_SOURCES: &[(&str, MastodonSource)] = &[
+    ("https://fosstodon.org", MastodonSource::Tag("rust")),
+    ("https://hachyderm.io", MastodonSource::Tag("rustlang")),
+    ("https://fosstodon.org", MastodonSource::Tag("nixos")),
+    ("https://chaos.social", MastodonSource::Account("nixos_org")),
+];
+pub const MASTODON_CONCURRENT_FETCHES: usize = 4;
+/// Statuses requested per source; 40 is the API maximum per page.
+pub const MASTODON_STATUSES_PER_SOURCE: usize = 40;
+/// Only posts newer than this are ranked, so an old viral post does not stick around.
+pub const MASTODON_WINDOW_SECS: u64 = 3 * 86400;
+pub const MASTODON_POSTS_PER_SOURCE: usize = 30;
+pub const MASTODON_ALL_VIEW_LIMIT: usize = 60;
+/// Post text is cut to this many characters in the panel.
+pub const MASTODON_EXCERPT_CHARS: usize = 280;
+
+// -- Bluesky --
+/// Public AppView: serves feeds and search without an account or token.
+pub const BLUESKY_APPVIEW_BASE: &str = "https://public.api.bsky.app/xrpc";
+
+/// What to read from the AppView.
+pub enum BlueskySource {
+    /// Feed generator, by its `at://` URI (shown on the feed's page on bsky.app).
+    Feed(&'static str),
+    /// Post search query, ranked by Bluesky's "top" sort.
+    Search(&'static str),
+}
+
+/// (label, source). Labels appear in the panel's select and key the feed map.
+pub const BLUESKY_SOURCES: &[(&str, BlueskySource)] = &[
+    ("rust", BlueskySource::Search("#rustlang")),
+    ("nix", BlueskySource::Search("#nixos")),
+    ("devops", BlueskySource::Search("#devops")),
+    (
+        "discover",
+        BlueskySource::Feed(
+            "at://did:plc:z72i7hdynmk6r22z27h6tvur/app.bsky.feed.generator/whats-hot",
+        ),
+    ),
+];
+pub const BLUESKY_CONCURRENT_FETCHES: usize = 4;
+pub const BLUESKY_POSTS_PER_SOURCE: usize = 30;
+pub const BLUESKY_ALL_VIEW_LIMIT: usize = 60;
+/// Bluesky caps posts at 300 graphemes; this only trims pathological ones.
+pub const BLUESKY_EXCERPT_CHARS: usize = 300;
+
+// -- Stack Exchange --
+pub const STACKEXCHANGE_API_BASE: &str = "https://api.stackexchange.com/2.3";
+/// (label, API site parameter, optional tag). Labels key the panel's site select.
+pub const STACKEXCHANGE_SITES: &[(&str, &str, Option<&str>)] = &[
+    ("rust", "stackoverflow", Some("rust")),
+    ("unix", "unix", None),
+    ("serverfault", "serverfault", None),
+];
+/// Question sorts, shown as tabs: the API's "hot" and "week" (top of the week).
+pub const STACKEXCHANGE_SORTS: &[&str] = &["hot", "week"];
+pub const STACKEXCHANGE_QUESTIONS_PER_SITE: usize = 30;
+pub const STACKEXCHANGE_ALL_VIEW_LIMIT: usize = 60;
+/// The API throttles bursts per IP; requests are spaced even when no `backoff` is sent.
+pub const STACKEXCHANGE_REQUEST_INTERVAL_MS: u64 = 500;
+pub const STACKEXCHANGE_REQUEST_JITTER_MS: u64 = 100;
+/// Warn when the daily quota left drops below this.
+pub const STACKEXCHANGE_QUOTA_WARN: u32 = 50;
+
+/// Optional app key (`STACKEXCHANGE_KEY`): raises the daily quota from 300 to 10,000.
+pub fn stackexchange_key() -> Option<String> {
+    std::env::var("STACKEXCHANGE_KEY")
+        .ok()
+        .filter(|s| !s.is_empty())
+}
+
+// -- Service status --
+/// Where a vendor publishes its status.
+pub enum StatusSource {
+    /// Atlassian Statuspage base URL; `/api/v2/summary.json` is appended.
+    Statuspage(&'static str),
+    /// Incident RSS/Atom feed, for vendors without a Statuspage summary.
+    Rss {
+        feed: &'static str,
+        page: &'static str,
+    },
+}
+
+pub const STATUS_PAGES: &[(&str, StatusSource)] = &[
+    (
+        "GitHub",
+        StatusSource::Statuspage("https://www.githubstatus.com"),
+    ),
+    (
+        "Cloudflare",
+        StatusSource::Statuspage("https://www.cloudflarestatus.com"),
+    ),
+    (
+        "Fly.io",
+        StatusSource::Statuspage("https://status.flyio.net"),
+    ),
+    ("npm", StatusSource::Statuspage("https://status.npmjs.org")),
+    (
+        "Discord",
+        StatusSource::Statuspage("https://discordstatus.com"),
+    ),
+    (
+        "AWS",
+        StatusSource::Rss {
+            feed: "https://status.aws.amazon.com/rss/all.rss",
+            page: "https://health.aws.amazon.com/health/status",
+        },
+    ),
+];
+/// RSS-only services count as degraded while an event is younger than this.
+pub const STATUS_RSS_ACTIVE_HOURS: u64 = 6;
+
+// -- Security advisories --
+/// (label, GitHub Advisory Database ecosystem id).
+pub const ADVISORY_ECOSYSTEMS: &[(&str, &str)] = &[
+    ("crates.io", "rust"),
+    ("npm", "npm"),
+    ("PyPI", "pip"),
+    ("Go", "go"),
+];
+pub const ADVISORIES_PER_ECOSYSTEM: usize = 30;
+pub const ADVISORIES_ALL_VIEW_LIMIT: usize = 60;
+/// Select value for advisories affecting packages in `ADVISORY_LOCKFILES`.
+pub const ADVISORY_DEPS_VIEW: &str = "our deps";
+
+/// Comma-separated `Cargo.lock` / `package-lock.json` paths (`ADVISORY_LOCKFILES`).
+/// When set, the advisories panel gets an extra view limited to those packages.
+pub fn advisory_lockfiles() -> Vec<String> {
+    std::env::var("ADVISORY_LOCKFILES")
+        .unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+// -- arXiv --
+pub const ARXIV_API_BASE: &str = "https://export.arxiv.org/api/query";
+pub const ARXIV_CATEGORIES: &[&str] = &["cs.PL", "cs.DC", "cs.CR"];
+pub const ARXIV_RESULTS_PER_CATEGORY: usize = 30;
+/// arXiv asks API clients for no more than one request every 3 seconds. The jitter
+/// only lengthens the gap: 3.5s ± 0.5s never drops below the 3s floor.
+pub const ARXIV_REQUEST_INTERVAL_MS: u64 = 3500;
+pub const ARXIV_REQUEST_JITTER_MS: u64 = 500;
+pub const ARXIV_AUTHORS_SHOWN: usize = 3;
+pub const ARXIV_ABSTRACT_CHARS: usize = 400;
+pub const ARXIV_ALL_VIEW_LIMIT: usize = 60;
+
+// -- Hugging Face --
+pub const HF_API_BASE: &str = "https://huggingface.co/api";
+/// Listings sorted by the Hub's trending score, as on huggingface.co's front page.
+pub const HF_TABS: &[&str] = &["models", "datasets", "spaces"];
+pub const HF_PER_TAB: usize = 25;
+
+// -- Lemmy --
+/// Communities as `name@instance`; each is read from its home instance's API.
+pub const LEMMY_COMMUNITIES: &[&str] = &[
+    "rust@programming.dev",
+    "programming@programming.dev",
+    "linux@lemmy.ml",
+    "selfhosted@lemmy.world",
+    "nix@programming.dev",
+];
+/// Lemmy sort type: "Hot", "Active", "TopDay", "TopWeek", ...
+pub const LEMMY_SORT: &str = "Hot";
+pub const LEMMY_POSTS_PER_COMMUNITY: usize = 30;
+pub const LEMMY_ALL_VIEW_LIMIT: usize = 100;
+/// Instances are volunteer-run; keep requests spaced like the Reddit scraper.
+pub const LEMMY_REQUEST_INTERVAL_MS: u64 = 500;
+pub const LEMMY_REQUEST_JITTER_MS: u64 = 150;
+
+// -- Tildes --
+pub const TILDES_BASE: &str = "https://tildes.net";
+/// Group listings include their subgroups (`~comp` also lists `~comp.programming`).
+pub const TILDES_GROUPS: &[&str] = &["~comp", "~tech"];
+/// Listing order: "activity", "votes", "comments" or "new".
+pub const TILDES_ORDER: &str = "activity";
+pub const TILDES_TOPICS_PER_GROUP: usize = 30;
+pub const TILDES_ALL_VIEW_LIMIT: usize = 60;
+/// Tildes is a small non-profit site with no API; scrape gently.
+pub const TILDES_REQUEST_INTERVAL_MS: u64 = 1000;
+pub const TILDES_REQUEST_JITTER_MS: u64 = 250;
+
+// -- Discourse --
+/// (label, forum base URL, category slugs). No categories means the whole forum;
+/// otherwise each category is fetched and the results are merged.
+pub const DISCOURSE_FORUMS: &[(&str, &str, &[&str])] = &[
+    ("rust users", "https://users.rust-lang.org", &[]),
+    ("rust internals", "https://internals.rust-lang.org", &[]),
+    (
+        "nixos",
+        "https://discourse.nixos.org",
+        &["announcements", "development"],
+    ),
+];
+/// Topic lists, shown as tabs: `/latest.json` and `/top.json?period=weekly`.
+pub const DISCOURSE_TABS: &[&str] = &["latest", "top"];
+pub const DISCOURSE_TOPICS_PER_FORUM: usize = 30;
+pub const DISCOURSE_ALL_VIEW_LIMIT: usize = 60;
+/// Anonymous API traffic shares Discourse's per-IP rate limit with page views.
+pub const DISCOURSE_REQUEST_INTERVAL_MS: u64 = 500;
+pub const DISCOURSE_REQUEST_JITTER_MS: u64 = 150;
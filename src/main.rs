@@ -20,13 +20,27 @@ async fn main() {
     let html = cache::new_shared_html();
     let client = client::build_client();
 
-    tokio::spawn(worker::run_scraper(
-        data.clone(),
-        html.clone(),
-        client,
-        Duration::from_secs(config::SCRAPE_INTERVAL_SECS),
-        config::HN_STORIES_PER_PAGE,
-    ));
+    match config::mirror_upstream() {
+        Some(upstream) => {
+            info!(upstream, "mirror mode, providers disabled");
+            tokio::spawn(worker::run_mirror(
+                data.clone(),
+                html.clone(),
+                client,
+                upstream,
+                Duration::from_secs(config::MIRROR_INTERVAL_SECS),
+            ));
+        }
+        None => {
+            tokio::spawn(worker::run_scraper(
+                data.clone(),
+                html.clone(),
+                client,
+                Duration::from_secs(config::SCRAPE_INTERVAL_SECS),
+                config::HN_STORIES_PER_PAGE,
+            ));
+        }
+    }
 
     tokio::spawn(worker::run_html_refresher(
         data.clone(),
@@ -0,0 +1,354 @@
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+use chrono::{DateTime, Utc};
+use reqwest::header::ACCEPT;
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+use url::Url;
+
+use super::{FetchError, github_pace};
+use crate::client::Client;
+use crate::config;
+
+/// Key: ecosystem label, "all", or `ADVISORY_DEPS_VIEW` when lockfiles are set.
+pub type AdvisoryFeed = HashMap<String, Vec<Advisory>>;
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Advisory {
+    pub ghsa_id: String,
+    #[serde(default)]
+    pub cve_id: Option<String>,
+    pub html_url: String,
+    pub summary: String,
+    /// "critical", "high", "medium", "low" or "unknown".
+    pub severity: String,
+    pub published_at: DateTime<Utc>,
+    #[serde(default)]
+    vulnerabilities: Vec<Vulnerability>,
+    /// Ecosystem label from `ADVISORY_ECOSYSTEMS`, filled in after parsing.
+    #[serde(default)]
+    pub ecosystem: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+struct Vulnerability {
+    package: Option<Package>,
+    /// e.g. `>= 1.0.0, < 1.4.3` or `= 2.0.0-rc.1`.
+    #[serde(default)]
+    vulnerable_version_range: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+struct Package {
+    name: String,
+}
+
+impl Advisory {
+    /// Affected package names, deduplicated in advisory order.
+    pub fn packages(&self) -> Vec<&str> {
+        let mut seen = HashSet::new();
+        self.vulnerabilities
+            .iter()
+            .filter_map(|v| v.package.as_ref())
+            .map(|p| p.name.as_str())
+            .filter(|name| seen.insert(*name))
+            .collect()
+    }
+}
+
+/// Newest reviewed advisories per ecosystem from the GitHub Advisory Database,
+/// which also carries every RustSec advisory for crates.io. One request per
+/// ecosystem fits easily inside the unauthenticated API limit.
+pub async fn fetch_advisories(client: &Client) -> AdvisoryFeed {
+    info!(
+        ecosystems = config::ADVISORY_ECOSYSTEMS.len(),
+        "advisories: fetching"
+    );
+
+    let token = config::github_token();
+    let mut feed = AdvisoryFeed::with_capacity(config::ADVISORY_ECOSYSTEMS.len() + 2);
+    for &(label, ecosystem) in config::ADVISORY_ECOSYSTEMS {
+        github_pace().await;
+        match fetch_ecosystem(client, ecosystem, token.as_deref()).await {
+            Ok(advisories) => {
+                let advisories = advisories
+                    .into_iter()
+                    .map(|a| Advisory {
+                        ecosystem: label.to_string(),
+                        ..a
+                    })
+                    .collect();
+                feed.insert(label.to_string(), advisories);
+            }
+            Err(e) => warn!(ecosystem = label, error = %e, "failed to fetch advisories"),
+        }
+    }
+
+    let mut all: Vec<Advisory> = feed.values().flatten().cloned().collect();
+    all.sort_by_key(|a| Reverse(a.published_at));
+
+    let lockfiles = config::advisory_lockfiles();
+    if !lockfiles.is_empty() {
+        let deps = tokio::task::spawn_blocking(move || read_lockfiles(&lockfiles))
+            .await
+            .unwrap_or_default();
+        let ours = all.iter().filter(|a| affects(a, &deps)).cloned().collect();
+        feed.insert(config::ADVISORY_DEPS_VIEW.to_string(), ours);
+    }
+
+    all.truncate(config::ADVISORIES_ALL_VIEW_LIMIT);
+    feed.insert(config::FILTER_ALL.to_string(), all);
+    feed
+}
+
+async fn fetch_ecosystem(
+    client: &Client,
+    ecosystem: &str,
+    token: Option<&str>,
+) -> Result<Vec<Advisory>, FetchError> {
+    let per_page = config::ADVISORIES_PER_ECOSYSTEM.to_string();
+    let url = Url::parse_with_params(
+        &format!("{}/advisories", config::GITHUB_API_BASE),
+        [
+            ("ecosystem", ecosystem),
+            ("type", "reviewed"),
+            ("sort", "published"),
+            ("direction", "desc"),
+            ("per_page", &per_page),
+        ],
+    )?;
+
+    let mut request = client
+        .get(url)
+        .header(ACCEPT, "application/vnd.github+json");
+    if let Some(token) = token {
+        request = request.bearer_auth(token);
+    }
+    Ok(request.send().await?.error_for_status()?.json().await?)
+}
+
+/// Locked versions per (GitHub ecosystem id, package name) from the configured
+/// lockfiles. A package can be locked at several versions at once.
+type Dependencies = HashMap<(&'static str, String), Vec<String>>;
+
+/// Whether a locked version of one of our dependencies falls in the advisory's
+/// vulnerable range, so upgrading past the fix drops it from the view.
+fn affects(advisory: &Advisory, deps: &Dependencies) -> bool {
+    let Some(&(_, ecosystem)) = config::ADVISORY_ECOSYSTEMS
+        .iter()
+        .find(|(label, _)| *label == advisory.ecosystem)
+    else {
+        return false;
+    };
+    advisory.vulnerabilities.iter().any(|v| {
+        let Some(package) = &v.package else {
+            return false;
+        };
+        let Some(locked) = deps.get(&(ecosystem, package.name.clone())) else {
+            return false;
+        };
+        match v.vulnerable_version_range.as_deref() {
+            Some(range) => locked.iter().any(|version| in_range(version, range)),
+            None => true,
+        }
+    })
+}
+
+/// Checks `version` against every comma-separated constraint of `range`.
+/// Anything unparseable counts as a match: an unread range must not hide an
+/// advisory for a package we depend on.
+fn in_range(version: &str, range: &str) -> bool {
+    let Some(version) = parse_semver(version) else {
+        return true;
+    };
+    range.split(',').all(|constraint| {
+        let constraint = constraint.trim();
+        let split = constraint
+            .find(|c: char| !matches!(c, '<' | '>' | '='))
+            .unwrap_or(constraint.len());
+        let (op, bound) = constraint.split_at(split);
+        let Some(bound) = parse_semver(bound.trim()) else {
+            return true;
+        };
+        match op {
+            "<" => version < bound,
+            "<=" => version <= bound,
+            ">" => version > bound,
+            ">=" => version >= bound,
+            "=" | "" => version == bound,
+            _ => true,
+        }
+    })
+}
+
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+enum PreIdent {
+    Numeric(u64),
+    Alpha(String),
+}
+
+/// A sortable semver key: `x.y.z` (missing parts are 0), then releases above
+/// their prereleases, then prerelease identifiers in semver order. Build
+/// metadata is ignored.
+type SemVer = ([u64; 3], bool, Vec<PreIdent>);
+
+fn parse_semver(s: &str) -> Option<SemVer> {
+    let s = s.trim_start_matches('v');
+    let s = s.split_once('+').map_or(s, |(v, _)| v);
+    let (core, pre) = s.split_once('-').map_or((s, None), |(c, p)| (c, Some(p)));
+    let mut parts = [0; 3];
+    for (i, part) in core.split('.').enumerate() {
+        *parts.get_mut(i)? = part.parse().ok()?;
+    }
+    let pre: Vec<PreIdent> = pre
+        .into_iter()
+        .flat_map(|p| p.split('.'))
+        .map(|id| {
+            id.parse()
+                .map_or_else(|_| PreIdent::Alpha(id.to_string()), PreIdent::Numeric)
+        })
+        .collect();
+    Some((parts, pre.is_empty(), pre))
+}
+
+/// `Cargo.lock` maps to crates.io and `package-lock.json` to npm; anything else
+/// is skipped with a warning. Unreadable files only drop their own packages.
+fn read_lockfiles(paths: &[String]) -> Dependencies {
+    let mut deps = Dependencies::new();
+    let mut add = |ecosystem, packages: Vec<(String, String)>| {
+        for (name, version) in packages {
+            deps.entry((ecosystem, name)).or_default().push(version);
+        }
+    };
+    for path in paths {
+        let contents = match std::fs::read_to_string(path) {
+            Ok(c) => c,
+            Err(e) => {
+                warn!(path, error = %e, "advisories: failed to read lockfile");
+                continue;
+            }
+        };
+        match Path::new(path).file_name().and_then(|n| n.to_str()) {
+            Some("Cargo.lock") => add("rust", parse_cargo_lock(&contents)),
+            Some("package-lock.json") => add("npm", parse_package_lock(&contents)),
+            _ => warn!(
+                path,
+                "advisories: unsupported lockfile, expected Cargo.lock or package-lock.json"
+            ),
+        }
+    }
+    deps
+}
+
+/// (name, version) pairs. Every `[[package]]` table has exactly one
+/// `name = "..."` line followed by its `version = "..."` line.
+fn parse_cargo_lock(contents: &str) -> Vec<(String, String)> {
+    let quoted = |line: &str, key: &str| {
+        line.strip_prefix(key)?
+            .strip_prefix(" = \"")?
+            .strip_suffix('"')
+            .map(str::to_string)
+    };
+    let mut packages = Vec::new();
+    let mut name = None;
+    for line in contents.lines() {
+        if let Some(n) = quoted(line, "name") {
+            name = Some(n);
+        } else if let Some(version) = quoted(line, "version")
+            && let Some(name) = name.take()
+        {
+            packages.push((name, version));
+        }
+    }
+    packages
+}
+
+/// (name, version) pairs. lockfileVersion 2/3 key `packages` by install path
+/// (`node_modules/a/node_modules/b`); version 1 only has the nested `dependencies` tree.
+fn parse_package_lock(contents: &str) -> Vec<(String, String)> {
+    #[derive(Deserialize)]
+    struct Lock {
+        #[serde(default)]
+        packages: HashMap<String, Entry>,
+        #[serde(default)]
+        dependencies: HashMap<String, Entry>,
+    }
+
+    #[derive(Deserialize)]
+    struct Entry {
+        #[serde(default)]
+        version: Option<String>,
+    }
+
+    let Ok(lock) = serde_json::from_str::<Lock>(contents) else {
+        warn!("advisories: package-lock.json is not valid JSON");
+        return Vec::new();
+    };
+    let from_packages = lock.packages.into_iter().filter_map(|(path, entry)| {
+        let name = path.rsplit_once("node_modules/")?.1.to_string();
+        Some((name, entry.version?))
+    });
+    let from_dependencies = lock
+        .dependencies
+        .into_iter()
+        .filter_map(|(name, entry)| Some((name, entry.version?)));
+    from_packages.chain(from_dependencies).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn advisories_parse_and_match_lockfile_deps() {
+        let body = r#"[{
+            "ghsa_id": "GHSA-abcd-1234-wxyz",
+            "cve_id": null,
+            "html_url": "https://github.com/advisories/GHSA-abcd-1234-wxyz",
+            "summary": "Use-after-free in claws::Pinch",
+            "type": "reviewed",
+            "severity": "high",
+            "published_at": "2026-10-17T12:00:00Z",
+            "vulnerabilities": [
+                { "package": { "ecosystem": "rust", "name": "claws" },
+                  "vulnerable_version_range": "< 1.4.3", "first_patched_version": "1.4.3" },
+                { "package": { "ecosystem": "rust", "name": "claws" },
+                  "vulnerable_version_range": "= 2.0.0-rc.1" }
+            ]
+        }]"#;
+        let mut advisories: Vec<Advisory> = serde_json::from_str(body).unwrap();
+        advisories[0].ecosystem = "crates.io".to_string();
+        assert_eq!(advisories[0].packages(), ["claws"]);
+
+        let cargo_lock = "version = 4\n\n[[package]]\nname = \"claws\"\nversion = \"1.4.2\"\n";
+        let package_lock = r#"{ "lockfileVersion": 3, "packages": {
+            "": { "name": "app" },
+            "node_modules/left-pad": { "version": "1.3.0" },
+            "node_modules/a/node_modules/@scope/b": { "version": "2.0.0" }
+        } }"#;
+        let pair = |name: &str, version: &str| (name.to_string(), version.to_string());
+        assert_eq!(parse_cargo_lock(cargo_lock), [pair("claws", "1.4.2")]);
+        let mut npm = parse_package_lock(package_lock);
+        npm.sort();
+        assert_eq!(npm, [pair("@scope/b", "2.0.0"), pair("left-pad", "1.3.0")]);
+
+        let locked_at = |versions: &[&str]| -> Dependencies {
+            let versions = versions.iter().map(|v| v.to_string()).collect();
+            [(("rust", "claws".to_string()), versions)].into()
+        };
+        assert!(affects(&advisories[0], &locked_at(&["1.4.2"])));
+        assert!(affects(&advisories[0], &locked_at(&["2.0.0-rc.1"])));
+        // Upgraded past the fix: no longer ours to worry about.
+        assert!(!affects(&advisories[0], &locked_at(&["1.4.3"])));
+        assert!(!affects(&advisories[0], &locked_at(&["2.0.0"])));
+        // One of two locked copies is still vulnerable.
+        assert!(affects(&advisories[0], &locked_at(&["1.5.0", "1.0.1"])));
+        assert!(!affects(&advisories[0], &Dependencies::new()));
+
+        assert!(in_range("1.2.0", ">= 1.0.0, < 1.4.3"));
+        assert!(!in_range("0.9.9", ">= 1.0.0, < 1.4.3"));
+        assert!(in_range("2.0.0-alpha.2", "< 2.0.0-beta"));
+    }
+}
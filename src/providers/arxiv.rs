@@ -0,0 +1,195 @@
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet};
+
+use chrono::{DateTime, Utc};
+use roxmltree::{Document, Node};
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use super::{FetchError, excerpt, jittered_delay};
+use crate::client::Client;
+use crate::config;
+
+/// Key: category (e.g. "cs.PL") or "all" for the merged view.
+pub type ArxivFeed = HashMap<String, Vec<ArxivPaper>>;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArxivPaper {
+    /// Versionless arXiv id, e.g. "2410.01234"; deduplicates cross-listed papers.
+    pub id: String,
+    pub title: String,
+    /// First `ARXIV_AUTHORS_SHOWN` authors.
+    pub authors: Vec<String>,
+    /// Total author count, to render "et al." when some are cut.
+    pub author_count: usize,
+    pub primary_category: String,
+    pub summary: String,
+    pub abs_url: String,
+    pub pdf_url: String,
+    pub published: DateTime<Utc>,
+}
+
+/// Newest submissions per category, fetched one request at a time per arXiv's
+/// API guidance. Cross-listed papers appear under every category they list.
+pub async fn fetch_arxiv_feed(client: &Client) -> ArxivFeed {
+    tracing::info!(
+        categories = config::ARXIV_CATEGORIES.len(),
+        "arxiv: fetching"
+    );
+
+    let mut feed = ArxivFeed::with_capacity(config::ARXIV_CATEGORIES.len() + 1);
+    for (i, &category) in config::ARXIV_CATEGORIES.iter().enumerate() {
+        if i > 0 {
+            jittered_delay(
+                config::ARXIV_REQUEST_INTERVAL_MS,
+                config::ARXIV_REQUEST_JITTER_MS,
+            )
+            .await;
+        }
+        match fetch_category(client, category).await {
+            Ok(papers) => {
+                feed.insert(category.to_string(), papers);
+            }
+            Err(e) => warn!(category, error = %e, "failed to fetch arxiv category"),
+        }
+    }
+
+    let mut seen = HashSet::new();
+    let mut all: Vec<ArxivPaper> = config::ARXIV_CATEGORIES
+        .iter()
+        .filter_map(|&c| feed.get(c))
+        .flatten()
+        .filter(|p| seen.insert(p.id.clone()))
+        .cloned()
+        .collect();
+    all.sort_by_key(|p| Reverse(p.published));
+    all.truncate(config::ARXIV_ALL_VIEW_LIMIT);
+    feed.insert(config::FILTER_ALL.to_string(), all);
+    feed
+}
+
+async fn fetch_category(client: &Client, category: &str) -> Result<Vec<ArxivPaper>, FetchError> {
+    let url = format!(
+        "{}?search_query=cat:{category}&sortBy=submittedDate&sortOrder=descending&max_results={}",
+        config::ARXIV_API_BASE,
+        config::ARXIV_RESULTS_PER_CATEGORY
+    );
+    let body = client
+        .get(&url)
+        .send()
+        .await?
+        .error_for_status()?
+        .text()
+        .await?;
+
+    // spawn_blocking: XML parsing is CPU-bound.
+    let papers = tokio::task::spawn_blocking(move || parse_listing(&body)).await??;
+    Ok(papers)
+}
+
+/// The API answers with an Atom feed; arXiv-specific fields live in the
+/// `arxiv:` namespace and the PDF is a second `<link title="pdf">`.
+fn parse_listing(xml: &str) -> Result<Vec<ArxivPaper>, roxmltree::Error> {
+    let doc = Document::parse(xml)?;
+    Ok(doc
+        .root_element()
+        .children()
+        .filter(|n| n.tag_name().name() == "entry")
+        .filter_map(parse_entry)
+        .collect())
+}
+
+fn parse_entry(entry: Node) -> Option<ArxivPaper> {
+    // <id>http://arxiv.org/abs/2410.01234v2</id>
+    let id_url = child_text(entry, "id")?;
+    let id = id_url.rsplit("/abs/").next()?;
+    let id = id.rsplit_once('v').map_or(id, |(base, _)| base).to_string();
+
+    let link = |attr: &str, value: &str| {
+        entry
+            .children()
+            .filter(|n| n.tag_name().name() == "link")
+            .find(|n| n.attribute(attr) == Some(value))
+            .and_then(|n| n.attribute("href"))
+            .map(str::to_string)
+    };
+
+    let all_authors: Vec<String> = entry
+        .children()
+        .filter(|n| n.tag_name().name() == "author")
+        .filter_map(|a| child_text(a, "name"))
+        .collect();
+
+    Some(ArxivPaper {
+        // Titles and abstracts are hard-wrapped at ~80 columns in the feed.
+        title: excerpt(&child_text(entry, "title")?, usize::MAX),
+        author_count: all_authors.len(),
+        authors: all_authors
+            .into_iter()
+            .take(config::ARXIV_AUTHORS_SHOWN)
+            .collect(),
+        primary_category: entry
+            .children()
+            .find(|n| n.tag_name().name() == "primary_category")
+            .and_then(|n| n.attribute("term"))
+            .unwrap_or_default()
+            .to_string(),
+        summary: excerpt(
+            &child_text(entry, "summary").unwrap_or_default(),
+            config::ARXIV_ABSTRACT_CHARS,
+        ),
+        abs_url: link("rel", "alternate").unwrap_or_else(|| format!("https://arxiv.org/abs/{id}")),
+        pdf_url: link("title", "pdf").unwrap_or_else(|| format!("https://arxiv.org/pdf/{id}")),
+        published: child_text(entry, "published")
+            .and_then(|d| DateTime::parse_from_rfc3339(&d).ok())
+            .map(|d| d.with_timezone(&Utc))
+            .unwrap_or_default(),
+        id,
+    })
+}
+
+fn child_text(node: Node, name: &str) -> Option<String> {
+    node.children()
+        .find(|n| n.tag_name().name() == name)
+        .and_then(|n| n.text())
+        .map(|t| t.trim().to_string())
+        .filter(|t| !t.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_atom_listing_with_pdf_link() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+            <feed xmlns="http://www.w3.org/2005/Atom" xmlns:arxiv="http://arxiv.org/schemas/atom">
+              <title>arXiv Query: search_query=cat:cs.PL</title>
+              <entry>
+                <id>http://arxiv.org/abs/2410.01234v2</id>
+                <published>2026-10-16T17:59:01Z</published>
+                <title>Ownership Types for
+                  Everyone</title>
+                <summary>  We present a type system
+                  that ...</summary>
+                <author><name>A. One</name></author>
+                <author><name>B. Two</name></author>
+                <author><name>C. Three</name></author>
+                <author><name>D. Four</name></author>
+                <link href="http://arxiv.org/abs/2410.01234v2" rel="alternate" type="text/html"/>
+                <link title="pdf" href="http://arxiv.org/pdf/2410.01234v2" rel="related" type="application/pdf"/>
+                <arxiv:primary_category term="cs.PL" scheme="http://arxiv.org/schemas/atom"/>
+                <category term="cs.PL" scheme="http://arxiv.org/schemas/atom"/>
+              </entry>
+            </feed>"#;
+        let papers = parse_listing(xml).unwrap();
+        let paper = &papers[0];
+        assert_eq!(paper.id, "2410.01234");
+        assert_eq!(paper.title, "Ownership Types for Everyone");
+        assert_eq!(paper.summary, "We present a type system that ...");
+        assert_eq!(paper.authors, ["A. One", "B. Two", "C. Three"]);
+        assert_eq!(paper.author_count, 4);
+        assert_eq!(paper.primary_category, "cs.PL");
+        assert_eq!(paper.pdf_url, "http://arxiv.org/pdf/2410.01234v2");
+    }
+}
@@ -0,0 +1,205 @@
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet};
+
+use chrono::{DateTime, Utc};
+use futures::stream::{self, StreamExt};
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+use url::Url;
+
+use super::{FetchError, excerpt};
+use crate::client::Client;
+use crate::config::{self, BlueskySource};
+
+/// Key: source label from `BLUESKY_SOURCES`, or "all" for the merged view.
+pub type BlueskyFeed = HashMap<String, Vec<BlueskyPost>>;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BlueskyPost {
+    /// `at://` URI; stable across feeds, so it deduplicates the merged view.
+    pub uri: String,
+    pub url: String,
+    pub handle: String,
+    pub text: String,
+    pub created_at: DateTime<Utc>,
+    pub likes: u64,
+    pub reposts: u64,
+    pub replies: u64,
+}
+
+/// `app.bsky.feed.defs#postView`, trimmed to the fields the panel shows.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PostView {
+    uri: String,
+    author: Author,
+    record: Record,
+    #[serde(default)]
+    like_count: u64,
+    #[serde(default)]
+    repost_count: u64,
+    #[serde(default)]
+    reply_count: u64,
+}
+
+#[derive(Deserialize)]
+struct Author {
+    handle: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Record {
+    #[serde(default)]
+    text: String,
+    created_at: DateTime<Utc>,
+}
+
+/// `app.bsky.feed.getFeed` wraps each post with feed context; search returns bare posts.
+#[derive(Deserialize)]
+struct FeedResponse {
+    feed: Vec<FeedViewPost>,
+}
+
+#[derive(Deserialize)]
+struct FeedViewPost {
+    post: PostView,
+}
+
+#[derive(Deserialize)]
+struct SearchResponse {
+    posts: Vec<PostView>,
+}
+
+impl From<PostView> for BlueskyPost {
+    fn from(post: PostView) -> Self {
+        // at://did/app.bsky.feed.post/{rkey} → bsky.app/profile/{handle}/post/{rkey}
+        let rkey = post.uri.rsplit('/').next().unwrap_or_default();
+        Self {
+            url: format!(
+                "https://bsky.app/profile/{}/post/{rkey}",
+                post.author.handle
+            ),
+            uri: post.uri,
+            handle: post.author.handle,
+            text: excerpt(&post.record.text, config::BLUESKY_EXCERPT_CHARS),
+            created_at: post.record.created_at,
+            likes: post.like_count,
+            reposts: post.repost_count,
+            replies: post.reply_count,
+        }
+    }
+}
+
+/// Fetch every configured feed and search concurrently. Each source keeps
+/// Bluesky's own order; the merged view ranks by likes + reposts.
+pub async fn fetch_bluesky_feed(client: &Client) -> BlueskyFeed {
+    tracing::info!(sources = config::BLUESKY_SOURCES.len(), "bluesky: fetching");
+
+    let fetched: Vec<_> = stream::iter(0..config::BLUESKY_SOURCES.len())
+        .map(|i| {
+            let client = client.clone();
+            async move {
+                let (label, ref source) = config::BLUESKY_SOURCES[i];
+                let posts = fetch_source(&client, source).await.unwrap_or_else(|e| {
+                    warn!(source = label, error = %e, "failed to fetch bluesky source");
+                    Vec::new()
+                });
+                (label, posts)
+            }
+        })
+        .buffer_unordered(config::BLUESKY_CONCURRENT_FETCHES)
+        .collect()
+        .await;
+
+    let mut feed = BlueskyFeed::with_capacity(fetched.len() + 1);
+    let mut seen = HashSet::new();
+    let mut all = Vec::new();
+    for (label, posts) in fetched {
+        all.extend(posts.iter().filter(|p| seen.insert(p.uri.clone())).cloned());
+        feed.insert(label.to_string(), posts);
+    }
+    all.sort_by_key(|p| Reverse(p.likes + p.reposts));
+    all.truncate(config::BLUESKY_ALL_VIEW_LIMIT);
+    feed.insert(config::FILTER_ALL.to_string(), all);
+    feed
+}
+
+async fn fetch_source(
+    client: &Client,
+    source: &BlueskySource,
+) -> Result<Vec<BlueskyPost>, FetchError> {
+    let base = config::BLUESKY_APPVIEW_BASE;
+    let limit = config::BLUESKY_POSTS_PER_SOURCE;
+    let posts = match *source {
+        BlueskySource::Feed(uri) => {
+            let url = Url::parse_with_params(
+                &format!("{base}/app.bsky.feed.getFeed"),
+                &[("feed", uri), ("limit", &limit.to_string())],
+            )?;
+            let resp: FeedResponse = client
+                .get(url)
+                .send()
+                .await?
+                .error_for_status()?
+                .json()
+                .await?;
+            resp.feed.into_iter().map(|item| item.post).collect()
+        }
+        BlueskySource::Search(q) => {
+            let url = Url::parse_with_params(
+                &format!("{base}/app.bsky.feed.searchPosts"),
+                &[("q", q), ("sort", "top"), ("limit", &limit.to_string())],
+            )?;
+            let resp: SearchResponse = client
+                .get(url)
+                .send()
+                .await?
+                .error_for_status()?
+                .json()
+                .await?;
+            resp.posts
+        }
+    };
+    Ok(posts.into_iter().map(BlueskyPost::from).collect::<Vec<_>>())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn post_views_map_to_web_urls() {
+        let post = r#"{
+            "uri": "at://did:plc:abc/app.bsky.feed.post/3kxyz",
+            "cid": "bafy",
+            "author": { "did": "did:plc:abc", "handle": "ferris.bsky.social" },
+            "record": {
+                "$type": "app.bsky.feed.post",
+                "text": "cargo 2.0\n\nis   here",
+                "createdAt": "2026-10-18T10:00:00.000Z"
+            },
+            "likeCount": 12,
+            "repostCount": 3,
+            "indexedAt": "2026-10-18T10:00:01.000Z"
+        }"#;
+
+        let search: SearchResponse =
+            serde_json::from_str(&format!(r#"{{ "posts": [{post}] }}"#)).unwrap();
+        let feed: FeedResponse =
+            serde_json::from_str(&format!(r#"{{ "feed": [{{ "post": {post} }}] }}"#)).unwrap();
+
+        for view in [
+            search.posts,
+            feed.feed.into_iter().map(|f| f.post).collect(),
+        ] {
+            let post = BlueskyPost::from(view.into_iter().next().unwrap());
+            assert_eq!(
+                post.url,
+                "https://bsky.app/profile/ferris.bsky.social/post/3kxyz"
+            );
+            assert_eq!(post.text, "cargo 2.0 is here");
+            assert_eq!((post.likes, post.reposts, post.replies), (12, 3, 0));
+        }
+    }
+}
@@ -0,0 +1,181 @@
+use std::collections::HashMap;
+use std::process::Stdio;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tokio::process::Command;
+use tracing::{info, warn};
+
+use super::excerpt;
+use crate::config;
+
+/// Key: panel name from `COMMAND_PANELS`.
+pub type CommandFeed = HashMap<String, CommandPanel>;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommandPanel {
+    pub items: Vec<ExternalItem>,
+    pub status: CommandStatus,
+}
+
+/// An item from outside the built-in providers. Shown in the order given.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ExternalItem {
+    #[serde(default)]
+    pub id: Option<String>,
+    pub title: String,
+    #[serde(default)]
+    pub url: Option<String>,
+    #[serde(default)]
+    pub author: Option<String>,
+    #[serde(default)]
+    pub score: Option<i64>,
+    #[serde(default)]
+    pub timestamp: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommandStatus {
+    /// Exited 0 and printed valid JSON.
+    pub ok: bool,
+    /// "ok", "exit status: 1", "timed out after 30s", "invalid JSON: ...".
+    pub message: String,
+    /// Trimmed to `COMMAND_STDERR_CHARS`.
+    pub stderr: String,
+    pub ran_at: DateTime<Utc>,
+}
+
+/// What a command prints to stdout: `{"items": [...]}`, each item shaped like
+/// `ExternalItem` (only `title` required, `timestamp` in epoch seconds). The
+/// README has a full example.
+#[derive(Deserialize)]
+struct Output {
+    items: Vec<ExternalItem>,
+}
+
+/// Runs every `COMMAND_PANELS` entry concurrently. Stderr becomes the panel's
+/// status either way; a failed run (start error, non-zero exit, timeout, bad
+/// JSON) keeps the previous cycle's items. A successful empty list clears them.
+pub async fn run_all(previous: &CommandFeed) -> CommandFeed {
+    if config::COMMAND_PANELS.is_empty() {
+        return CommandFeed::new();
+    }
+    info!(commands = config::COMMAND_PANELS.len(), "commands: running");
+
+    let timeout = Duration::from_secs(config::COMMAND_TIMEOUT_SECS);
+    let runs = config::COMMAND_PANELS
+        .iter()
+        .map(|&(panel, program, args)| async move { (panel, run(program, args, timeout).await) });
+
+    futures::future::join_all(runs)
+        .await
+        .into_iter()
+        .map(|(panel, (items, status))| {
+            let items = match items {
+                Some(items) => items,
+                None => {
+                    warn!(
+                        panel,
+                        status = status.message,
+                        stderr = status.stderr,
+                        "command failed, keeping previous"
+                    );
+                    previous
+                        .get(panel)
+                        .map(|p| p.items.clone())
+                        .unwrap_or_default()
+                }
+            };
+            (panel.to_string(), CommandPanel { items, status })
+        })
+        .collect()
+}
+
+/// Returns `None` for the items when the run failed.
+async fn run(
+    program: &str,
+    args: &[&str],
+    timeout: Duration,
+) -> (Option<Vec<ExternalItem>>, CommandStatus) {
+    let ran_at = Utc::now();
+    let status = |ok: bool, message: String, stderr: &[u8]| CommandStatus {
+        ok,
+        message,
+        stderr: excerpt(
+            String::from_utf8_lossy(stderr).trim(),
+            config::COMMAND_STDERR_CHARS,
+        ),
+        ran_at,
+    };
+
+    // kill_on_drop: a timed-out child is killed when its future is dropped.
+    let child = Command::new(program)
+        .args(args)
+        .stdin(Stdio::null())
+        .kill_on_drop(true)
+        .output();
+    let output = match tokio::time::timeout(timeout, child).await {
+        Ok(Ok(output)) => output,
+        Ok(Err(e)) => return (None, status(false, format!("failed to start: {e}"), &[])),
+        Err(_) => {
+            let message = format!("timed out after {}s", timeout.as_secs());
+            return (None, status(false, message, &[]));
+        }
+    };
+
+    if !output.status.success() {
+        return (
+            None,
+            status(false, output.status.to_string(), &output.stderr),
+        );
+    }
+    match serde_json::from_slice::<Output>(&output.stdout) {
+        Ok(mut parsed) => {
+            parsed.items.truncate(config::COMMAND_ITEMS_LIMIT);
+            (
+                Some(parsed.items),
+                status(true, "ok".to_string(), &output.stderr),
+            )
+        }
+        Err(e) => (
+            None,
+            status(false, format!("invalid JSON: {e}"), &output.stderr),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TIMEOUT: Duration = Duration::from_secs(5);
+
+    #[tokio::test]
+    async fn parses_stdout_and_captures_failures() {
+        let script = r#"echo 'warming cache' >&2
+            echo '{"items": [{"id": "a", "title": "Flaky: upload", "score": 4, "timestamp": 1792314000}, {"title": "bare"}]}'"#;
+        let (items, status) = run("sh", &["-c", script], TIMEOUT).await;
+        let items = items.unwrap();
+        assert!(status.ok);
+        assert_eq!(status.stderr, "warming cache");
+        assert_eq!(items[0].id.as_deref(), Some("a"));
+        assert_eq!(items[0].score, Some(4));
+        assert_eq!(items[1].title, "bare");
+        assert_eq!(items[1].url, None);
+
+        let (items, status) = run("sh", &["-c", "echo 'no token' >&2; exit 3"], TIMEOUT).await;
+        assert!(items.is_none());
+        assert!(!status.ok);
+        assert!(status.message.contains('3'));
+        assert_eq!(status.stderr, "no token");
+
+        let (items, status) = run("sh", &["-c", "echo '{\"items\": [{}]}'"], TIMEOUT).await;
+        assert!(items.is_none());
+        assert!(status.message.starts_with("invalid JSON"));
+
+        let (items, status) = run("sleep", &["5"], Duration::from_millis(100)).await;
+        assert!(items.is_none());
+        assert!(status.message.starts_with("timed out"));
+    }
+}
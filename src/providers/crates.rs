@@ -0,0 +1,193 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use reqwest::header::{ACCEPT, USER_AGENT};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use super::{FetchError, jittered_delay};
+use crate::client::Client;
+use crate::config;
+
+/// Key: tab name ("new", "updated", "trending") or "watchlist" for the pinned crates.
+pub type CratesFeed = HashMap<String, Vec<CrateInfo>>;
+
+pub const WATCHLIST_KEY: &str = "watchlist";
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CrateInfo {
+    pub name: String,
+    pub version: String,
+    pub description: Option<String>,
+    pub downloads: u64,
+    /// Downloads over the last 90 days. crates.io omits it from some summary lists.
+    pub recent_downloads: Option<u64>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl CrateInfo {
+    pub fn url(&self) -> String {
+        format!("https://crates.io/crates/{}", self.name)
+    }
+}
+
+/// A crate as the crates.io API names its fields. `CrateInfo` keeps ours, so
+/// the snapshot mirrors pull doesn't follow API renames.
+#[derive(Deserialize)]
+struct ApiCrate {
+    name: String,
+    #[serde(default)]
+    newest_version: String,
+    #[serde(default)]
+    description: Option<String>,
+    #[serde(default)]
+    downloads: u64,
+    #[serde(default)]
+    recent_downloads: Option<u64>,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<ApiCrate> for CrateInfo {
+    fn from(krate: ApiCrate) -> Self {
+        Self {
+            name: krate.name,
+            version: krate.newest_version,
+            description: krate.description,
+            downloads: krate.downloads,
+            recent_downloads: krate.recent_downloads,
+            updated_at: krate.updated_at,
+        }
+    }
+}
+
+fn to_infos(crates: Vec<ApiCrate>) -> Vec<CrateInfo> {
+    crates.into_iter().map(CrateInfo::from).collect()
+}
+
+#[derive(Deserialize)]
+struct Summary {
+    #[serde(default)]
+    new_crates: Vec<ApiCrate>,
+    #[serde(default)]
+    just_updated: Vec<ApiCrate>,
+}
+
+#[derive(Deserialize)]
+struct CrateList {
+    crates: Vec<ApiCrate>,
+}
+
+#[derive(Deserialize)]
+struct CrateResponse {
+    #[serde(rename = "crate")]
+    krate: ApiCrate,
+}
+
+/// Fetches the summary lists, the recent-downloads ranking, and every watchlist crate.
+/// Requests are strictly sequential to honour the crawler policy; failures are per-request.
+pub async fn fetch_crates_feed(client: &Client) -> CratesFeed {
+    tracing::info!(
+        watchlist = config::CRATES_WATCHLIST.len(),
+        "crates.io: fetching"
+    );
+
+    let mut feed = CratesFeed::with_capacity(config::CRATES_TABS.len() + 1);
+
+    match get_json::<Summary>(client, &format!("{}/summary", config::CRATES_API_BASE)).await {
+        Ok(summary) => {
+            feed.insert("new".to_string(), to_infos(summary.new_crates));
+            feed.insert("updated".to_string(), to_infos(summary.just_updated));
+        }
+        Err(e) => warn!(error = %e, "failed to fetch crates.io summary"),
+    }
+
+    pace().await;
+    let url = format!(
+        "{}/crates?sort=recent-downloads&per_page={}",
+        config::CRATES_API_BASE,
+        config::CRATES_PER_TAB
+    );
+    match get_json::<CrateList>(client, &url).await {
+        Ok(list) => {
+            feed.insert("trending".to_string(), to_infos(list.crates));
+        }
+        Err(e) => warn!(error = %e, "failed to fetch crates.io trending"),
+    }
+
+    let mut watchlist = Vec::with_capacity(config::CRATES_WATCHLIST.len());
+    for &name in config::CRATES_WATCHLIST {
+        pace().await;
+        let url = format!("{}/crates/{name}", config::CRATES_API_BASE);
+        match get_json::<CrateResponse>(client, &url).await {
+            Ok(resp) => watchlist.push(resp.krate.into()),
+            Err(e) => warn!(krate = name, error = %e, "failed to fetch watched crate"),
+        }
+    }
+    feed.insert(WATCHLIST_KEY.to_string(), watchlist);
+
+    for crates in feed.values_mut() {
+        crates.truncate(config::CRATES_PER_TAB);
+    }
+    feed
+}
+
+/// crates.io's crawler policy: identify the app in the User-Agent (the shared
+/// client's browser UA is overridden here) and ask for JSON explicitly.
+async fn get_json<T: DeserializeOwned>(client: &Client, url: &str) -> Result<T, FetchError> {
+    Ok(client
+        .get(url)
+        .header(USER_AGENT, config::CRATES_USER_AGENT)
+        .header(ACCEPT, "application/json")
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?)
+}
+
+async fn pace() {
+    jittered_delay(
+        config::CRATES_REQUEST_INTERVAL_MS,
+        config::CRATES_REQUEST_JITTER_MS,
+    )
+    .await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn summary_parses_new_and_updated() {
+        let body = r#"{
+            "num_crates": 1,
+            "new_crates": [{
+                "name": "tiny-crate",
+                "newest_version": "0.1.0",
+                "description": null,
+                "downloads": 12,
+                "recent_downloads": null,
+                "updated_at": "2026-10-18T09:30:00.123456+00:00"
+            }],
+            "just_updated": [{
+                "name": "tokio",
+                "newest_version": "1.52.3",
+                "description": "An event-driven, non-blocking I/O platform",
+                "downloads": 500000000,
+                "recent_downloads": 60000000,
+                "updated_at": "2026-10-18T12:00:00+00:00"
+            }]
+        }"#;
+        let summary: Summary = serde_json::from_str(body).unwrap();
+        let (new, updated) = (to_infos(summary.new_crates), to_infos(summary.just_updated));
+
+        let new = &new[0];
+        assert_eq!(new.version, "0.1.0");
+        assert!(new.description.is_none() && new.recent_downloads.is_none());
+
+        let updated = &updated[0];
+        assert_eq!(updated.url(), "https://crates.io/crates/tokio");
+        assert_eq!(updated.recent_downloads, Some(60_000_000));
+    }
+}
@@ -0,0 +1,239 @@
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use super::{FetchError, jittered_delay};
+use crate::client::Client;
+use crate::config;
+
+/// Key: (forum label or "all", tab).
+pub type DiscourseFeed = HashMap<(String, String), Vec<DiscourseTopic>>;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiscourseTopic {
+    pub id: u64,
+    pub title: String,
+    pub url: String,
+    /// Forum label from `DISCOURSE_FORUMS`.
+    pub forum: String,
+    pub replies: u64,
+    pub likes: u64,
+    pub views: u64,
+    pub last_activity: DateTime<Utc>,
+}
+
+#[derive(Deserialize)]
+struct TopicListResponse {
+    topic_list: TopicList,
+}
+
+#[derive(Deserialize)]
+struct TopicList {
+    topics: Vec<Topic>,
+}
+
+#[derive(Deserialize)]
+struct Topic {
+    id: u64,
+    title: String,
+    slug: String,
+    #[serde(default)]
+    posts_count: u64,
+    #[serde(default)]
+    like_count: u64,
+    #[serde(default)]
+    views: u64,
+    #[serde(default)]
+    last_posted_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    bumped_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pinned: bool,
+}
+
+/// Fetch every forum (or its configured categories) for every tab, sequentially.
+pub async fn fetch_discourse_feed(client: &Client) -> DiscourseFeed {
+    tracing::info!(
+        forums = config::DISCOURSE_FORUMS.len(),
+        "discourse: fetching"
+    );
+
+    let mut feed = DiscourseFeed::new();
+    let mut first = true;
+    for &(label, base, categories) in config::DISCOURSE_FORUMS {
+        for &tab in config::DISCOURSE_TABS {
+            // `None` is the forum-wide list.
+            let scopes: Vec<Option<&str>> = if categories.is_empty() {
+                vec![None]
+            } else {
+                categories.iter().copied().map(Some).collect()
+            };
+
+            let mut lists = Vec::with_capacity(scopes.len());
+            for category in scopes {
+                if !first {
+                    jittered_delay(
+                        config::DISCOURSE_REQUEST_INTERVAL_MS,
+                        config::DISCOURSE_REQUEST_JITTER_MS,
+                    )
+                    .await;
+                }
+                first = false;
+                match fetch_topics(client, base, category, tab).await {
+                    Ok(list) => lists.push(parse_topics(list, base, label)),
+                    Err(e) => {
+                        warn!(forum = label, category, tab, error = %e, "failed to fetch discourse topics")
+                    }
+                }
+            }
+            // A topic can be listed under a category and its subcategory.
+            let mut seen = HashSet::new();
+            let mut topics = merge_for_tab(lists, tab);
+            topics.retain(|t| seen.insert(t.id));
+            topics.truncate(config::DISCOURSE_TOPICS_PER_FORUM);
+            feed.insert((label.to_string(), tab.to_string()), topics);
+        }
+    }
+
+    for &tab in config::DISCOURSE_TABS {
+        let lists = config::DISCOURSE_FORUMS
+            .iter()
+            .filter_map(|&(label, _, _)| feed.get(&(label.to_string(), tab.to_string())))
+            .cloned()
+            .collect();
+        let mut all = merge_for_tab(lists, tab);
+        all.truncate(config::DISCOURSE_ALL_VIEW_LIMIT);
+        feed.insert((config::FILTER_ALL.to_string(), tab.to_string()), all);
+    }
+
+    feed
+}
+
+/// Merges the lists of several categories or forums. "latest" is re-sorted by
+/// activity. "top" keeps Discourse's weekly ranking of each list and
+/// interleaves them by rank; the topics' like counts are all-time and would
+/// lose it. A single list comes back in API order either way.
+fn merge_for_tab(mut lists: Vec<Vec<DiscourseTopic>>, tab: &str) -> Vec<DiscourseTopic> {
+    if lists.len() == 1 {
+        return lists.pop().unwrap_or_default();
+    }
+    if tab != "top" {
+        let mut merged: Vec<DiscourseTopic> = lists.into_iter().flatten().collect();
+        merged.sort_by_key(|t| Reverse(t.last_activity));
+        return merged;
+    }
+    let mut lists: Vec<_> = lists.into_iter().map(Vec::into_iter).collect();
+    let mut merged = Vec::new();
+    loop {
+        let before = merged.len();
+        merged.extend(lists.iter_mut().filter_map(Iterator::next));
+        if merged.len() == before {
+            return merged;
+        }
+    }
+}
+
+async fn fetch_topics(
+    client: &Client,
+    base: &str,
+    category: Option<&str>,
+    tab: &str,
+) -> Result<TopicListResponse, FetchError> {
+    let path = match category {
+        Some(slug) => format!("/c/{slug}/l/{tab}.json"),
+        None => format!("/{tab}.json"),
+    };
+    let period = if tab == "top" { "?period=weekly" } else { "" };
+    Ok(client
+        .get(format!("{base}{path}{period}"))
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?)
+}
+
+fn parse_topics(list: TopicListResponse, base: &str, forum: &str) -> Vec<DiscourseTopic> {
+    list.topic_list
+        .topics
+        .into_iter()
+        // Pinned topics are forum guidelines and announcements, not discussion.
+        .filter(|t| !t.pinned)
+        .map(|t| DiscourseTopic {
+            url: format!("{base}/t/{}/{}", t.slug, t.id),
+            id: t.id,
+            title: t.title,
+            forum: forum.to_string(),
+            // posts_count includes the opening post.
+            replies: t.posts_count.saturating_sub(1),
+            likes: t.like_count,
+            views: t.views,
+            last_activity: t.last_posted_at.or(t.bumped_at).unwrap_or_default(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn topic_list_parses_and_skips_pinned() {
+        let body = r#"{
+            "users": [{ "id": 1, "username": "ferris" }],
+            "topic_list": {
+                "can_create_topic": false,
+                "per_page": 30,
+                "topics": [
+                    { "id": 1, "title": "Welcome", "slug": "welcome", "posts_count": 1,
+                      "pinned": true, "views": 9000, "like_count": 50,
+                      "last_posted_at": "2026-01-01T00:00:00.000Z" },
+                    { "id": 1234, "title": "Async drop status?", "slug": "async-drop-status",
+                      "posts_count": 12, "reply_count": 4, "like_count": 31, "views": 870,
+                      "pinned": false, "last_posted_at": null,
+                      "bumped_at": "2026-10-18T09:00:00.000Z" }
+                ]
+            }
+        }"#;
+        let list: TopicListResponse = serde_json::from_str(body).unwrap();
+        let topics = parse_topics(list, "https://internals.rust-lang.org", "rust internals");
+
+        assert_eq!(topics.len(), 1);
+        let topic = &topics[0];
+        assert_eq!(
+            topic.url,
+            "https://internals.rust-lang.org/t/async-drop-status/1234"
+        );
+        assert_eq!((topic.replies, topic.likes, topic.views), (11, 31, 870));
+        assert_eq!(topic.last_activity.timestamp(), 1792314000);
+    }
+
+    #[test]
+    fn weekly_top_lists_interleave_by_rank() {
+        let topic = |id: u64, likes: u64| DiscourseTopic {
+            id,
+            title: format!("Topic {id}"),
+            url: String::new(),
+            forum: String::new(),
+            replies: 0,
+            likes,
+            views: 0,
+            last_activity: DateTime::UNIX_EPOCH,
+        };
+        // Weekly rank order, with all-time likes that disagree with it.
+        let first = vec![topic(1, 3), topic(2, 900)];
+        let second = vec![topic(10, 5), topic(11, 40), topic(12, 7)];
+
+        let merged = merge_for_tab(vec![first.clone()], "top");
+        assert_eq!(merged.iter().map(|t| t.id).collect::<Vec<_>>(), [1, 2]);
+
+        let merged = merge_for_tab(vec![first, second], "top");
+        assert_eq!(
+            merged.iter().map(|t| t.id).collect::<Vec<_>>(),
+            [1, 10, 2, 11, 12]
+        );
+    }
+}
@@ -0,0 +1,260 @@
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet};
+
+use chrono::DateTime;
+use futures::stream::{self, StreamExt};
+use roxmltree::{Document, Node, ParsingOptions};
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use super::FetchError;
+use crate::client::Client;
+use crate::config;
+
+/// Key: (panel, feed name) or (panel, "all") for the merged per-panel view.
+pub type Feeds = HashMap<(String, String), Vec<FeedItem>>;
+
+/// Common item model for RSS 2.0, RSS 1.0 (RDF) and Atom entries.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FeedItem {
+    /// RSS `<guid>` / Atom `<id>`, falling back to the link when the feed has neither.
+    pub guid: String,
+    pub title: String,
+    pub link: String,
+    pub author: Option<String>,
+    /// Epoch seconds; 0 when the feed gives no parseable date.
+    pub published: u64,
+    /// Display name of the configured feed the item came from.
+    pub feed: String,
+}
+
+/// Fetch every configured feed, merging each with its items from the previous
+/// cycle so entries are deduplicated by GUID rather than replaced wholesale.
+pub async fn fetch_all_feeds(client: &Client, previous: &Feeds) -> Feeds {
+    // (panel index, feed index) pairs: plain indices keep the stream closure free
+    // of borrowed `&str`s, which trip rustc's higher-ranked lifetime inference
+    // once the scraper future is spawned.
+    let jobs: Vec<(usize, usize)> = config::FEED_PANELS
+        .iter()
+        .enumerate()
+        .flat_map(|(p, &(_, feeds))| (0..feeds.len()).map(move |f| (p, f)))
+        .collect();
+    tracing::info!(feeds = jobs.len(), "feeds: fetching");
+
+    let fetched: Vec<_> = stream::iter(jobs)
+        .map(|(p, f)| {
+            let client = client.clone();
+            async move {
+                let (panel, feeds) = config::FEED_PANELS[p];
+                let (name, url) = feeds[f];
+                let items = fetch_feed(&client, name, url).await.unwrap_or_else(|e| {
+                    warn!(feed = name, error = %e, "failed to fetch feed");
+                    Vec::new()
+                });
+                (panel, name, items)
+            }
+        })
+        .buffer_unordered(config::FEED_CONCURRENT_FETCHES)
+        .collect()
+        .await;
+
+    let mut out = Feeds::with_capacity(fetched.len() + config::FEED_PANELS.len());
+    for (panel, name, items) in fetched {
+        let key = (panel.to_string(), name.to_string());
+        let previous = previous.get(&key).map_or(&[][..], Vec::as_slice);
+        out.insert(key, merge_by_guid(items, previous));
+    }
+
+    // Merged per-panel view: newest first, deduplicated across feeds in the panel.
+    for &(panel, feeds) in config::FEED_PANELS {
+        let mut seen = HashSet::new();
+        let mut all: Vec<FeedItem> = feeds
+            .iter()
+            .filter_map(|&(name, _)| out.get(&(panel.to_string(), name.to_string())))
+            .flatten()
+            .filter(|item| seen.insert(item.guid.clone()))
+            .cloned()
+            .collect();
+        all.sort_by_key(|item| Reverse(item.published));
+        all.truncate(config::FEED_ALL_VIEW_LIMIT);
+        out.insert((panel.to_string(), config::FILTER_ALL.to_string()), all);
+    }
+
+    out
+}
+
+/// Fresh items win over carried-over ones with the same GUID. Items that fell off
+/// the feed since the last cycle stay until newer ones push them past the limit.
+fn merge_by_guid(fresh: Vec<FeedItem>, previous: &[FeedItem]) -> Vec<FeedItem> {
+    let mut seen = HashSet::new();
+    let mut merged: Vec<FeedItem> = fresh
+        .into_iter()
+        .chain(previous.iter().cloned())
+        .filter(|item| seen.insert(item.guid.clone()))
+        .collect();
+    merged.sort_by_key(|item| Reverse(item.published));
+    merged.truncate(config::FEED_ITEMS_PER_FEED);
+    merged
+}
+
+async fn fetch_feed(client: &Client, name: &str, url: &str) -> Result<Vec<FeedItem>, FetchError> {
+    let body = client
+        .get(url)
+        .send()
+        .await?
+        .error_for_status()?
+        .text()
+        .await?;
+
+    let name = name.to_string();
+    // spawn_blocking: XML parsing is CPU-bound.
+    let items = tokio::task::spawn_blocking(move || parse_feed(&body, &name)).await??;
+    Ok(items)
+}
+
+pub(crate) fn parse_feed(xml: &str, feed: &str) -> Result<Vec<FeedItem>, roxmltree::Error> {
+    // Some feeds still ship a DOCTYPE; roxmltree rejects those unless DTDs are allowed.
+    let options = ParsingOptions {
+        allow_dtd: true,
+        ..ParsingOptions::default()
+    };
+    let doc = Document::parse_with_options(xml, options)?;
+    let root = doc.root_element();
+
+    let items: Vec<FeedItem> = if root.tag_name().name() == "feed" {
+        root.children()
+            .filter(|n| n.tag_name().name() == "entry")
+            .filter_map(|n| parse_atom_entry(n, feed))
+            .collect()
+    } else {
+        // RSS 2.0 nests items in <channel>; RSS 1.0 puts them beside it.
+        root.descendants()
+            .filter(|n| n.tag_name().name() == "item")
+            .filter_map(|n| parse_rss_item(n, feed))
+            .collect()
+    };
+
+    // Detect non-feed responses: a real feed always has at least one entry.
+    if items.is_empty() && xml.len() > 1000 {
+        warn!(
+            feed,
+            xml_len = xml.len(),
+            "feeds: 0 items parsed on non-empty document — not a feed?"
+        );
+    }
+
+    Ok(items
+        .into_iter()
+        .take(config::FEED_ITEMS_PER_FEED)
+        .collect())
+}
+
+fn parse_rss_item(item: Node, feed: &str) -> Option<FeedItem> {
+    let title = child_text(item, "title")?;
+    let link = child_text(item, "link").unwrap_or_default();
+    let guid = child_text(item, "guid").unwrap_or_else(|| link.clone());
+    if guid.is_empty() {
+        return None;
+    }
+    Some(FeedItem {
+        guid,
+        title,
+        link,
+        // RSS 2.0 <author> is an email address; most feeds use <dc:creator> instead.
+        author: child_text(item, "creator").or_else(|| child_text(item, "author")),
+        published: child_text(item, "pubDate")
+            .or_else(|| child_text(item, "date"))
+            .map_or(0, |d| parse_date(&d)),
+        feed: feed.to_string(),
+    })
+}
+
+fn parse_atom_entry(entry: Node, feed: &str) -> Option<FeedItem> {
+    let title = child_text(entry, "title")?;
+    let link = entry
+        .children()
+        .filter(|n| n.tag_name().name() == "link")
+        .find(|n| n.attribute("rel").is_none_or(|rel| rel == "alternate"))
+        .and_then(|n| n.attribute("href"))
+        .unwrap_or_default()
+        .to_string();
+    let guid = child_text(entry, "id").unwrap_or_else(|| link.clone());
+    if guid.is_empty() {
+        return None;
+    }
+    Some(FeedItem {
+        guid,
+        title,
+        link,
+        author: entry
+            .children()
+            .find(|n| n.tag_name().name() == "author")
+            .and_then(|a| child_text(a, "name")),
+        published: child_text(entry, "published")
+            .or_else(|| child_text(entry, "updated"))
+            .map_or(0, |d| parse_date(&d)),
+        feed: feed.to_string(),
+    })
+}
+
+/// First non-empty text of a child matched by local name, so namespaced
+/// elements (`dc:creator`, `atom:link`) match without namespace bookkeeping.
+fn child_text(node: Node, name: &str) -> Option<String> {
+    node.children()
+        .filter(|n| n.tag_name().name() == name)
+        .find_map(|n| {
+            let text = n.text()?.trim();
+            (!text.is_empty()).then(|| text.to_string())
+        })
+}
+
+/// RSS uses RFC 2822 dates, Atom and Dublin Core use RFC 3339.
+fn parse_date(s: &str) -> u64 {
+    DateTime::parse_from_rfc2822(s)
+        .or_else(|_| DateTime::parse_from_rfc3339(s))
+        .map_or(0, |d| d.timestamp().max(0) as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_rss_and_atom_into_common_model() {
+        let rss = r#"<?xml version="1.0"?>
+            <rss version="2.0" xmlns:dc="http://purl.org/dc/elements/1.1/"><channel>
+              <title>LWN</title>
+              <item>
+                <title><![CDATA[Kernel prepatch 6.20-rc1]]></title>
+                <link>https://lwn.net/Articles/1/</link>
+                <guid isPermaLink="false">lwn-1</guid>
+                <dc:creator>corbet</dc:creator>
+                <pubDate>Sun, 18 Oct 2026 10:00:00 +0000</pubDate>
+              </item>
+            </channel></rss>"#;
+        let items = parse_feed(rss, "LWN").unwrap();
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].title, "Kernel prepatch 6.20-rc1");
+        assert_eq!(items[0].guid, "lwn-1");
+        assert_eq!(items[0].author.as_deref(), Some("corbet"));
+        assert!(items[0].published > 0);
+
+        let atom = r#"<feed xmlns="http://www.w3.org/2005/Atom">
+              <entry>
+                <title>This Week in Rust 600</title>
+                <link rel="alternate" href="https://this-week-in-rust.org/blog/600/"/>
+                <id>tag:twir,600</id>
+                <author><name>TWiR</name></author>
+                <updated>2026-10-15T00:00:00Z</updated>
+              </entry>
+            </feed>"#;
+        let items = parse_feed(atom, "TWiR").unwrap();
+        assert_eq!(items[0].link, "https://this-week-in-rust.org/blog/600/");
+        assert_eq!(items[0].guid, "tag:twir,600");
+        assert_eq!(items[0].author.as_deref(), Some("TWiR"));
+
+        // Re-fetching the same entry must not duplicate it.
+        let merged = merge_by_guid(items.clone(), &items);
+        assert_eq!(merged.len(), 1);
+    }
+}
@@ -0,0 +1,183 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+use tracing::{info, warn};
+use url::Url;
+
+use super::github::TrendingRepo;
+use super::{FetchError, jittered_delay};
+use crate::client::Client;
+use crate::config;
+
+/// Key: (sort, source label) e.g. ("stars", "codeberg"), mirroring `GhTrending`
+/// so the repos render with the GitHub panel's markup.
+pub type ForgeRepos = HashMap<(String, String), Vec<TrendingRepo>>;
+
+#[derive(Deserialize)]
+struct SearchResults {
+    #[serde(default)]
+    data: Vec<ApiRepo>,
+}
+
+#[derive(Deserialize)]
+struct ApiRepo {
+    name: String,
+    owner: Owner,
+    html_url: String,
+    #[serde(default)]
+    description: String,
+    #[serde(default)]
+    language: String,
+    #[serde(default)]
+    stars_count: u64,
+    #[serde(default)]
+    forks_count: u64,
+}
+
+#[derive(Deserialize)]
+struct Owner {
+    id: u64,
+    login: String,
+}
+
+/// Forgejo and Gitea share the `/api/v1` surface. Sources are often on the
+/// same community-run instance (Codeberg), so requests go out one at a time.
+pub async fn fetch_forge_repos(client: &Client) -> ForgeRepos {
+    info!(sources = config::FORGE_SOURCES.len(), "forgejo: fetching");
+
+    let mut feed =
+        ForgeRepos::with_capacity(config::FORGE_SOURCES.len() * config::FORGE_SORTS.len());
+    let mut first = true;
+    for &(label, base, owner) in config::FORGE_SOURCES {
+        let uid = match owner {
+            Some(owner) => {
+                pace(&mut first).await;
+                match fetch_owner_id(client, base, owner).await {
+                    Ok(id) => Some(id),
+                    Err(e) => {
+                        warn!(source = label, owner, error = %e, "failed to look up forge owner");
+                        continue;
+                    }
+                }
+            }
+            None => None,
+        };
+        for &sort in config::FORGE_SORTS {
+            pace(&mut first).await;
+            match search(client, base, sort, uid).await {
+                Ok(repos) => {
+                    feed.insert((sort.to_string(), label.to_string()), repos);
+                }
+                Err(e) => warn!(source = label, sort, error = %e, "failed to fetch forge repos"),
+            }
+        }
+    }
+    feed
+}
+
+async fn pace(first: &mut bool) {
+    if !std::mem::take(first) {
+        jittered_delay(
+            config::FORGE_REQUEST_INTERVAL_MS,
+            config::FORGE_REQUEST_JITTER_MS,
+        )
+        .await;
+    }
+}
+
+/// Organizations are users to the search endpoint's `uid` filter.
+async fn fetch_owner_id(client: &Client, base: &str, owner: &str) -> Result<u64, FetchError> {
+    let owner: Owner = client
+        .get(format!("{base}/api/v1/users/{owner}"))
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+    Ok(owner.id)
+}
+
+/// Instance-wide listings only include source repos; forks and mirrors of
+/// popular GitHub projects would otherwise crowd the star ranking.
+async fn search(
+    client: &Client,
+    base: &str,
+    sort: &str,
+    uid: Option<u64>,
+) -> Result<Vec<TrendingRepo>, FetchError> {
+    let limit = config::FORGE_REPOS_PER_SOURCE.to_string();
+    let mut url = Url::parse_with_params(
+        &format!("{base}/api/v1/repos/search"),
+        [("sort", sort), ("order", "desc"), ("limit", &limit)],
+    )?;
+    match uid {
+        Some(uid) => {
+            url.query_pairs_mut()
+                .append_pair("uid", &uid.to_string())
+                .append_pair("exclusive", "true");
+        }
+        None => {
+            url.query_pairs_mut().append_pair("mode", "source");
+        }
+    }
+
+    let results: SearchResults = client
+        .get(url)
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+    Ok(results.data.into_iter().map(to_trending_repo).collect())
+}
+
+/// Forges report no per-period star counts or contributor avatars; those
+/// fields stay empty and `render_gh_repo` skips them.
+fn to_trending_repo(repo: ApiRepo) -> TrendingRepo {
+    TrendingRepo {
+        author: repo.owner.login,
+        name: repo.name,
+        url: repo.html_url,
+        description: repo.description,
+        language: Some(repo.language).filter(|l| !l.is_empty()),
+        language_color: None,
+        stars: repo.stars_count,
+        forks: repo.forks_count,
+        period_stars: String::new(),
+        contributors: Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn search_results_map_to_trending_repos() {
+        let body = r#"{
+            "ok": true,
+            "data": [{
+                "id": 7, "name": "claws", "full_name": "ferris/claws",
+                "owner": { "id": 3, "login": "ferris", "full_name": "" },
+                "html_url": "https://codeberg.org/ferris/claws",
+                "description": "Pinch-safe allocators",
+                "language": "Rust", "stars_count": 412, "forks_count": 18,
+                "updated_at": "2026-10-18T09:00:00+02:00"
+            }, {
+                "id": 8, "name": "dotfiles",
+                "owner": { "id": 3, "login": "ferris" },
+                "html_url": "https://codeberg.org/ferris/dotfiles",
+                "description": "", "language": "", "stars_count": 2, "forks_count": 0
+            }]
+        }"#;
+        let results: SearchResults = serde_json::from_str(body).unwrap();
+        let repos: Vec<TrendingRepo> = results.data.into_iter().map(to_trending_repo).collect();
+
+        assert_eq!(repos[0].author, "ferris");
+        assert_eq!(repos[0].name, "claws");
+        assert_eq!(repos[0].language.as_deref(), Some("Rust"));
+        assert_eq!(repos[0].stars, 412);
+        assert_eq!(repos[1].language, None);
+        assert!(repos[1].contributors.is_empty());
+    }
+}
@@ -3,8 +3,9 @@ use std::sync::LazyLock;
 
 use crate::client::Client;
 use scraper::{Html, Selector};
+use serde::{Deserialize, Serialize};
 
-use super::{FetchError, jittered_delay, text};
+use super::{FetchError, github_pace, text};
 use crate::config;
 
 struct Selectors {
@@ -15,6 +16,7 @@ struct Selectors {
     lang_color: Selector,
     link: Selector,
     period: Selector,
+    built_by: Selector,
 }
 
 static SELECTORS: LazyLock<Option<Selectors>> = LazyLock::new(|| {
@@ -26,6 +28,7 @@ static SELECTORS: LazyLock<Option<Selectors>> = LazyLock::new(|| {
         lang_color: Selector::parse("span.repo-language-color").ok()?,
         link: Selector::parse(".f6.color-fg-muted a").ok()?,
         period: Selector::parse(".d-inline-block.float-sm-right").ok()?,
+        built_by: Selector::parse("a[data-hovercard-type='user']").ok()?,
     });
     if selectors.is_none() {
         tracing::error!("github: CSS selectors failed to parse — trending scraper is broken");
@@ -33,10 +36,36 @@ static SELECTORS: LazyLock<Option<Selectors>> = LazyLock::new(|| {
     selectors
 });
 
+/// `/trending/developers` rows: the developer, then a nested `<article>` with
+/// their popular repo.
+struct DeveloperSelectors {
+    article: Selector,
+    name: Selector,
+    login: Selector,
+    repo_link: Selector,
+    repo_desc: Selector,
+}
+
+static DEVELOPER_SELECTORS: LazyLock<Option<DeveloperSelectors>> = LazyLock::new(|| {
+    let selectors = Some(DeveloperSelectors {
+        article: Selector::parse("article.Box-row").ok()?,
+        name: Selector::parse("h1.h3 a").ok()?,
+        login: Selector::parse("p.f4 a").ok()?,
+        repo_link: Selector::parse("article h1.h4 a").ok()?,
+        repo_desc: Selector::parse("article div.f6").ok()?,
+    });
+    if selectors.is_none() {
+        tracing::error!(
+            "github: CSS selectors failed to parse — trending developers scraper is broken"
+        );
+    }
+    selectors
+});
+
 /// Key: (period, language) e.g. ("daily", "all"), ("weekly", "Rust")
 pub type GhTrending = HashMap<(String, String), Vec<TrendingRepo>>;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TrendingRepo {
     pub author: String,
     pub name: String,
@@ -47,6 +76,27 @@ pub struct TrendingRepo {
     pub stars: u64,
     pub forks: u64,
     pub period_stars: String,
+    /// Logins from the "Built by" avatars, in page order.
+    pub contributors: Vec<String>,
+}
+
+/// Key: (period, language), same as `GhTrending`.
+pub type GhDevelopers = HashMap<(String, String), Vec<TrendingDeveloper>>;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrendingDeveloper {
+    pub login: String,
+    /// Display name; falls back to the login when the profile has none.
+    pub name: String,
+    pub url: String,
+    pub popular_repo: Option<PopularRepo>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PopularRepo {
+    pub name: String,
+    pub url: String,
+    pub description: String,
 }
 
 /// GitHub has no public trending API — HTML scraping is the only option.
@@ -55,27 +105,53 @@ pub async fn fetch_trending(
     since: &str,
     language: Option<&str>,
 ) -> Result<Vec<TrendingRepo>, FetchError> {
+    let html_text = fetch_page(client, "", since, language, config::GITHUB_SPOKEN_LANGUAGE).await?;
+
+    // spawn_blocking: scraper HTML parsing is CPU-bound.
+    let repos = tokio::task::spawn_blocking(move || parse_trending_html(&html_text)).await?;
+    Ok(repos)
+}
+
+pub async fn fetch_trending_developers(
+    client: &Client,
+    since: &str,
+    language: Option<&str>,
+) -> Result<Vec<TrendingDeveloper>, FetchError> {
+    let html_text = fetch_page(client, "/developers", since, language, None).await?;
+    let developers = tokio::task::spawn_blocking(move || parse_developers_html(&html_text)).await?;
+    Ok(developers)
+}
+
+async fn fetch_page(
+    client: &Client,
+    view: &str,
+    since: &str,
+    language: Option<&str>,
+    spoken_language: Option<&str>,
+) -> Result<String, FetchError> {
     let lang_segment = language.map_or(String::new(), |l| format!("/{l}"));
+    let spoken = spoken_language.map_or(String::new(), |c| format!("&spoken_language_code={c}"));
     let url = format!(
-        "{}{}?since={}",
+        "{}{}{}?since={}{}",
         config::GITHUB_TRENDING_URL,
+        view,
         lang_segment,
-        since
+        since,
+        spoken
     );
-    let html_text = client
+    Ok(client
         .get(&url)
         .send()
         .await?
         .error_for_status()?
         .text()
-        .await?;
-
-    // spawn_blocking: scraper HTML parsing is CPU-bound.
-    let repos = tokio::task::spawn_blocking(move || parse_trending_html(&html_text)).await?;
-    Ok(repos)
+        .await?)
 }
 
-pub async fn fetch_all_trending(client: &Client) -> GhTrending {
+/// Scrapes both trending views for every (language, period). The developers
+/// view doubles the requests per cycle; `github_pace` keeps the request rate
+/// the same, so the cycle takes longer instead.
+pub async fn fetch_all_trending(client: &Client) -> (GhTrending, GhDevelopers) {
     tracing::info!(
         languages = config::GITHUB_LANGUAGES.len(),
         "github: fetching"
@@ -89,19 +165,13 @@ pub async fn fetch_all_trending(client: &Client) -> GhTrending {
 
     // Jitter-paced fetches: a randomized gap between requests to avoid tripping
     // GitHub's secondary limits on bursty unauthenticated scraping, and to avoid
-    // a fixed (bot-detectable) cadence. No delay before the first request.
+    // a fixed (bot-detectable) cadence. The gap is shared with releases and
+    // advisories, which hit GitHub at the same time.
     let mut trending = GhTrending::new();
-    let mut first = true;
+    let mut developers = GhDevelopers::new();
     for (name, slug) in languages {
         for &period in config::GITHUB_PERIODS {
-            if !first {
-                jittered_delay(
-                    config::GITHUB_REQUEST_INTERVAL_MS,
-                    config::GITHUB_REQUEST_JITTER_MS,
-                )
-                .await;
-            }
-            first = false;
+            github_pace().await;
             let repos = fetch_trending(client, period, slug)
                 .await
                 .unwrap_or_else(|e| {
@@ -109,9 +179,18 @@ pub async fn fetch_all_trending(client: &Client) -> GhTrending {
                     Vec::new()
                 });
             trending.insert((period.to_string(), name.to_string()), repos);
+
+            github_pace().await;
+            let devs = fetch_trending_developers(client, period, slug)
+                .await
+                .unwrap_or_else(|e| {
+                    tracing::warn!(error = %e, "github trending developers {period} {name}");
+                    Vec::new()
+                });
+            developers.insert((period.to_string(), name.to_string()), devs);
         }
     }
-    trending
+    (trending, developers)
 }
 
 fn parse_num(s: &str) -> u64 {
@@ -176,6 +255,14 @@ fn parse_trending_html(html: &str) -> Vec<TrendingRepo> {
             .map(text)
             .unwrap_or_default();
 
+        let contributors = article
+            .select(&sel.built_by)
+            .filter_map(|a| a.value().attr("href"))
+            .map(|href| href.trim_matches('/').to_string())
+            .filter(|login| !login.is_empty())
+            .take(config::GITHUB_CONTRIBUTORS_SHOWN)
+            .collect();
+
         if stars == 0 && forks == 0 {
             tracing::warn!(
                 repo = %format!("{author}/{name}"),
@@ -193,12 +280,74 @@ fn parse_trending_html(html: &str) -> Vec<TrendingRepo> {
             stars,
             forks,
             period_stars,
+            contributors,
         });
     }
 
     repos
 }
 
+fn parse_developers_html(html: &str) -> Vec<TrendingDeveloper> {
+    let Some(sel) = DEVELOPER_SELECTORS.as_ref() else {
+        tracing::error!("CSS selectors failed to parse");
+        return Vec::new();
+    };
+
+    let document = Html::parse_document(html);
+    let mut developers = Vec::with_capacity(config::GITHUB_REPOS_PER_PAGE);
+
+    // Same stale-markup check as the repo page.
+    if document.select(&sel.article).next().is_none() && html.len() > 1000 {
+        tracing::error!(
+            html_len = html.len(),
+            "github: 0 developers matched on non-empty page — CSS selectors may be stale"
+        );
+        return developers;
+    }
+
+    for article in document.select(&sel.article) {
+        let Some(name_link) = article.select(&sel.name).next() else {
+            continue;
+        };
+        let href = name_link.value().attr("href").unwrap_or_default();
+        let login = href.trim_matches('/');
+        if login.is_empty() {
+            continue;
+        }
+
+        let popular_repo = article.select(&sel.repo_link).next().and_then(|a| {
+            let href = a.value().attr("href")?;
+            Some(PopularRepo {
+                name: text(a),
+                url: format!("https://github.com{href}"),
+                description: article
+                    .select(&sel.repo_desc)
+                    .next()
+                    .map(text)
+                    .unwrap_or_default(),
+            })
+        });
+
+        // Profiles without a display name show the login in the heading and
+        // have no separate login line.
+        let name = text(name_link);
+        let login = article
+            .select(&sel.login)
+            .next()
+            .map(text)
+            .unwrap_or_else(|| login.to_string());
+
+        developers.push(TrendingDeveloper {
+            url: format!("https://github.com{href}"),
+            name,
+            login,
+            popular_repo,
+        });
+    }
+
+    developers
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -218,4 +367,70 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn parses_built_by_contributors() {
+        let html = r#"<html><body>
+            <article class="Box-row">
+              <h2 class="h3 lh-condensed"><a href="/ferris/claws">ferris / claws</a></h2>
+              <div class="f6 color-fg-muted mt-2">
+                <a class="Link--muted d-inline-block mr-3" href="/ferris/claws/stargazers">1,234</a>
+                <a class="Link--muted d-inline-block mr-3" href="/ferris/claws/forks">56</a>
+                <span class="d-inline-block mr-3">
+                  Built by
+                  <a class="d-inline-block" data-hovercard-type="user" href="/ferris"><img class="avatar" alt="@ferris"></a>
+                  <a class="d-inline-block" data-hovercard-type="user" href="/corro"><img class="avatar" alt="@corro"></a>
+                </span>
+                <span class="d-inline-block float-sm-right">78 stars today</span>
+              </div>
+            </article>
+        </body></html>"#;
+        let repos = parse_trending_html(html);
+        assert_eq!(repos[0].contributors, ["ferris", "corro"]);
+        assert_eq!((repos[0].stars, repos[0].forks), (1234, 56));
+    }
+
+    #[test]
+    fn parses_developer_rows_with_popular_repo() {
+        let html = r##"<html><body>
+            <article class="Box-row d-flex" id="pa-ferris">
+              <a class="color-fg-muted f6" href="#pa-ferris">1</a>
+              <div class="mx-3"><a href="/ferris"><img class="rounded avatar-user" alt="@ferris"></a></div>
+              <div class="d-sm-flex flex-auto">
+                <div class="col-sm-8 d-md-flex">
+                  <div class="col-md-6">
+                    <h1 class="h3 lh-condensed"><a href="/ferris">Ferris Crab</a></h1>
+                    <p class="f4 text-normal mb-1"><a class="Link--secondary" href="/ferris">ferris</a></p>
+                  </div>
+                  <div class="col-md-6">
+                    <article>
+                      <h1 class="h4 lh-condensed"><span>Popular repo</span></h1>
+                      <h1 class="h4 lh-condensed"><a href="/ferris/claws">
+                        claws
+                      </a></h1>
+                      <div class="f6 color-fg-muted mt-1">Pinch-free async runtime</div>
+                    </article>
+                  </div>
+                </div>
+              </div>
+            </article>
+            <article class="Box-row d-flex" id="pa-nameless">
+              <h1 class="h3 lh-condensed"><a href="/nameless">nameless</a></h1>
+            </article>
+        </body></html>"##;
+        let devs = parse_developers_html(html);
+        assert_eq!(devs.len(), 2);
+
+        assert_eq!(
+            (devs[0].login.as_str(), devs[0].name.as_str()),
+            ("ferris", "Ferris Crab")
+        );
+        let repo = devs[0].popular_repo.as_ref().unwrap();
+        assert_eq!(repo.name, "claws");
+        assert_eq!(repo.url, "https://github.com/ferris/claws");
+        assert_eq!(repo.description, "Pinch-free async runtime");
+
+        assert_eq!(devs[1].login, "nameless");
+        assert!(devs[1].popular_repo.is_none());
+    }
 }
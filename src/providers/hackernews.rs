@@ -1,44 +1,116 @@
+use std::cmp::Reverse;
 use std::collections::HashMap;
 
 use crate::client::Client;
+use chrono::Utc;
 use futures::stream::{self, StreamExt};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use tracing::warn;
 
 use super::FetchError;
-use crate::config;
+use crate::config::{self, HnSource};
 
-/// Per-page story data: key is page display name ("top", "newest", "show").
+/// Per-page story data: key is page display name ("top", "newest", "7d", ...).
 pub type HnPages = HashMap<String, Vec<HnStory>>;
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct HnStory {
-    #[serde(default)]
-    id: u64,
-    #[serde(default)]
+    pub id: u64,
     pub title: String,
-    #[serde(default)]
     pub url: Option<String>,
-    #[serde(skip)]
+    /// Set by `finalize`, not by the HN API.
     pub hn_url: String,
-    #[serde(default)]
     pub score: u32,
-    #[serde(default, rename(deserialize = "by"))]
     pub author: String,
-    #[serde(default, rename(deserialize = "time"))]
     pub created_at: u64,
-    #[serde(
-        default,
-        rename(deserialize = "descendants"),
-        deserialize_with = "deserialize_comment_count"
-    )]
     pub comment_count: u32,
-    #[serde(default, skip_deserializing)]
     pub domain: Option<String>,
 }
 
-fn deserialize_comment_count<'de, D: serde::Deserializer<'de>>(d: D) -> Result<u32, D::Error> {
-    Ok(Option::<u32>::deserialize(d)?.unwrap_or(0))
+impl HnStory {
+    fn finalize(mut self) -> Self {
+        self.hn_url = format!("https://news.ycombinator.com/item?id={}", self.id);
+        self.domain = self
+            .url
+            .as_deref()
+            .and_then(extract_domain)
+            .map(String::from);
+        self
+    }
+}
+
+/// Firebase `item/{id}.json`. Kept apart from `HnStory` so the snapshot mirrors
+/// pull uses our field names, not the API's.
+#[derive(Deserialize)]
+struct FirebaseItem {
+    #[serde(default)]
+    id: u64,
+    #[serde(default)]
+    title: String,
+    #[serde(default)]
+    url: Option<String>,
+    #[serde(default)]
+    score: u32,
+    #[serde(default)]
+    by: String,
+    #[serde(default)]
+    time: u64,
+    /// Null rather than zero on some stories.
+    #[serde(default)]
+    descendants: Option<u32>,
+}
+
+impl FirebaseItem {
+    fn into_story(self) -> HnStory {
+        HnStory {
+            id: self.id,
+            title: self.title,
+            url: self.url,
+            hn_url: String::new(),
+            score: self.score,
+            author: self.by,
+            created_at: self.time,
+            comment_count: self.descendants.unwrap_or(0),
+            domain: None,
+        }
+        .finalize()
+    }
+}
+
+/// Algolia search hit. Field names differ from Firebase, and nulls are common
+/// on old or flagged stories, so everything but the id is optional.
+#[derive(Deserialize)]
+struct AlgoliaHit {
+    #[serde(rename = "objectID")]
+    object_id: String,
+    title: Option<String>,
+    url: Option<String>,
+    points: Option<u32>,
+    author: Option<String>,
+    created_at_i: Option<u64>,
+    num_comments: Option<u32>,
+}
+
+#[derive(Deserialize)]
+struct AlgoliaResponse {
+    hits: Vec<AlgoliaHit>,
+}
+
+impl AlgoliaHit {
+    fn into_story(self) -> Option<HnStory> {
+        let story = HnStory {
+            id: self.object_id.parse().ok()?,
+            title: self.title.filter(|t| !t.is_empty())?,
+            url: self.url.filter(|u| !u.is_empty()),
+            hn_url: String::new(),
+            score: self.points.unwrap_or(0),
+            author: self.author.unwrap_or_default(),
+            created_at: self.created_at_i.unwrap_or(0),
+            comment_count: self.num_comments.unwrap_or(0),
+            domain: None,
+        };
+        Some(story.finalize())
+    }
 }
 
 /// Fetch all configured HN pages concurrently, returning a map of page name → stories.
@@ -52,13 +124,18 @@ pub async fn fetch_all_pages(client: &Client, count: usize) -> HnPages {
 
     let futs: Vec<_> = config::HN_PAGES
         .iter()
-        .map(|&(name, endpoint)| async move {
-            let stories = fetch_page(client, endpoint, count)
-                .await
-                .unwrap_or_else(|e| {
-                    warn!(page = name, error = %e, "failed to fetch HN page");
-                    Vec::new()
-                });
+        .map(|(name, source)| async move {
+            let result = match *source {
+                HnSource::Firebase(endpoint) => fetch_page(client, endpoint, count).await,
+                HnSource::Algolia {
+                    min_points,
+                    window_secs,
+                } => fetch_algolia(client, min_points, window_secs, count).await,
+            };
+            let stories = result.unwrap_or_else(|e| {
+                warn!(page = name, error = %e, "failed to fetch HN page");
+                Vec::new()
+            });
             (name.to_string(), stories)
         })
         .collect();
@@ -92,14 +169,38 @@ async fn fetch_page(
 }
 
 async fn fetch_item(client: &Client, url: &str) -> Result<HnStory, FetchError> {
-    let mut story: HnStory = client.get(url).send().await?.json().await?;
-    story.hn_url = format!("https://news.ycombinator.com/item?id={}", story.id);
-    story.domain = story
-        .url
-        .as_deref()
-        .and_then(extract_domain)
-        .map(String::from);
-    Ok(story)
+    let item: FirebaseItem = client.get(url).send().await?.json().await?;
+    Ok(item.into_story())
+}
+
+/// Firebase only exposes fixed lists; "best of the last N days" needs Algolia's
+/// numeric filters. One request returns full stories, so no per-item fan-out.
+async fn fetch_algolia(
+    client: &Client,
+    min_points: u32,
+    window_secs: u64,
+    count: usize,
+) -> Result<Vec<HnStory>, FetchError> {
+    let since = Utc::now().timestamp() - window_secs as i64;
+    let url = format!(
+        "{}/search?tags=story,front_page&numericFilters=points>{min_points},created_at_i>{since}&hitsPerPage={count}",
+        config::HN_ALGOLIA_BASE
+    );
+    let body: AlgoliaResponse = client
+        .get(&url)
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+
+    let mut stories: Vec<HnStory> = body
+        .hits
+        .into_iter()
+        .filter_map(AlgoliaHit::into_story)
+        .collect();
+    stories.sort_by_key(|s| Reverse(s.score));
+    Ok(stories)
 }
 
 /// Extract the domain from a URL, stripping any "www." prefix.
@@ -133,4 +234,26 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn algolia_hits_map_to_stories() {
+        let body = r#"{"hits": [
+            {"objectID": "42", "title": "Show HN: tty1", "url": "https://www.example.com/x",
+             "points": 512, "author": "pg", "created_at_i": 1760000000, "num_comments": 99},
+            {"objectID": "43", "title": null, "url": null, "points": null,
+             "author": null, "created_at_i": null, "num_comments": null}
+        ]}"#;
+        let body: AlgoliaResponse = serde_json::from_str(body).unwrap();
+        let stories: Vec<_> = body
+            .hits
+            .into_iter()
+            .filter_map(AlgoliaHit::into_story)
+            .collect();
+
+        assert_eq!(stories.len(), 1, "untitled hit should be dropped");
+        let story = &stories[0];
+        assert_eq!(story.hn_url, "https://news.ycombinator.com/item?id=42");
+        assert_eq!(story.domain.as_deref(), Some("example.com"));
+        assert_eq!((story.score, story.comment_count), (512, 99));
+    }
 }
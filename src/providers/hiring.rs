@@ -0,0 +1,320 @@
+use std::collections::HashMap;
+
+use futures::stream::{self, StreamExt};
+use scraper::Html;
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+
+use super::{FetchError, excerpt};
+use crate::client::Client;
+use crate::config;
+
+/// Key: (thread id, thread title). Holds a single entry, the newest thread.
+pub type HiringFeed = HashMap<(u64, String), Vec<Job>>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Workplace {
+    Remote,
+    Hybrid,
+    Onsite,
+    Unknown,
+}
+
+impl Workplace {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Remote => "remote",
+            Self::Hybrid => "hybrid",
+            Self::Onsite => "onsite",
+            Self::Unknown => "unknown",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Job {
+    pub company: String,
+    pub role: Option<String>,
+    pub location: Option<String>,
+    pub workplace: Workplace,
+    pub stack: Option<String>,
+    /// First paragraphs after the header line.
+    pub summary: String,
+    /// The comment on HN.
+    pub url: String,
+    pub posted: u64,
+    /// Labels from `HIRING_LOCATIONS` that match the header line.
+    #[serde(deserialize_with = "deserialize_regions")]
+    pub regions: Vec<&'static str>,
+    /// Labels from `HIRING_TECH` that match anywhere in the post.
+    #[serde(deserialize_with = "deserialize_tech")]
+    pub tech: Vec<&'static str>,
+}
+
+fn deserialize_regions<'de, D: serde::Deserializer<'de>>(
+    d: D,
+) -> Result<Vec<&'static str>, D::Error> {
+    Ok(known_labels(config::HIRING_LOCATIONS, Vec::deserialize(d)?))
+}
+
+fn deserialize_tech<'de, D: serde::Deserializer<'de>>(d: D) -> Result<Vec<&'static str>, D::Error> {
+    Ok(known_labels(config::HIRING_TECH, Vec::deserialize(d)?))
+}
+
+/// A mirrored job's labels come back as strings; only labels this build's
+/// filters offer are kept.
+fn known_labels(table: &[(&'static str, &[&str])], labels: Vec<String>) -> Vec<&'static str> {
+    table
+        .iter()
+        .map(|&(label, _)| label)
+        .filter(|label| labels.iter().any(|l| l == label))
+        .collect()
+}
+
+#[derive(Deserialize)]
+struct User {
+    #[serde(default)]
+    submitted: Vec<u64>,
+}
+
+#[derive(Deserialize)]
+struct Item {
+    id: u64,
+    #[serde(default)]
+    time: u64,
+    #[serde(default)]
+    title: Option<String>,
+    #[serde(default)]
+    text: Option<String>,
+    #[serde(default)]
+    kids: Vec<u64>,
+    #[serde(default)]
+    deleted: bool,
+    #[serde(default)]
+    dead: bool,
+}
+
+/// The `whoishiring` account posts three threads each month ("Who is hiring?",
+/// "Freelancer?", "Who wants to be hired?"); the newest "Who is hiring?" among
+/// its latest submissions is the current one.
+pub async fn fetch_hiring(client: &Client) -> HiringFeed {
+    info!("hiring: fetching");
+
+    let thread = match find_thread(client).await {
+        Ok(Some(thread)) => thread,
+        Ok(None) => {
+            warn!("hiring: no \"Who is hiring?\" thread among recent whoishiring submissions");
+            return HiringFeed::new();
+        }
+        Err(e) => {
+            warn!(error = %e, "hiring: failed to find thread");
+            return HiringFeed::new();
+        }
+    };
+
+    let comments: Vec<Item> = stream::iter(
+        thread
+            .kids
+            .iter()
+            .copied()
+            .take(config::HIRING_MAX_COMMENTS),
+    )
+    .map(|id| {
+        let client = client.clone();
+        async move {
+            fetch_item(&client, id)
+                .await
+                .map_err(|e| warn!("hn item {id}: {e}"))
+                .ok()
+        }
+    })
+    .buffered(config::HN_CONCURRENT_FETCHES)
+    .filter_map(|c| async { c })
+    .collect()
+    .await;
+
+    let total = comments.len();
+    let jobs: Vec<Job> = comments.into_iter().filter_map(parse_job).collect();
+    info!(comments = total, jobs = jobs.len(), "hiring: parsed thread");
+
+    let title = thread.title.unwrap_or_default();
+    HashMap::from([((thread.id, title), jobs)])
+}
+
+async fn find_thread(client: &Client) -> Result<Option<Item>, FetchError> {
+    let url = format!("{}/user/{}.json", config::HN_API_BASE, config::HIRING_USER);
+    let user: User = client
+        .get(&url)
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+
+    for &id in user
+        .submitted
+        .iter()
+        .take(config::HIRING_SUBMISSIONS_SCANNED)
+    {
+        let item = fetch_item(client, id).await?;
+        if item
+            .title
+            .as_deref()
+            .is_some_and(|t| t.starts_with(config::HIRING_THREAD_PREFIX))
+        {
+            return Ok(Some(item));
+        }
+    }
+    Ok(None)
+}
+
+async fn fetch_item(client: &Client, id: u64) -> Result<Item, FetchError> {
+    let url = format!("{}/item/{id}.json", config::HN_API_BASE);
+    Ok(client
+        .get(&url)
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?)
+}
+
+/// Posts follow `Company | Role | Location | REMOTE | Stack` on their first
+/// line, but the order drifts: workplace markers and URLs are pulled out
+/// wherever they appear, and the rest fill role, location, stack in order.
+/// Comments without a `|` header aren't job posts (meta replies, complaints).
+fn parse_job(item: Item) -> Option<Job> {
+    if item.deleted || item.dead {
+        return None;
+    }
+    let html = item.text?;
+    let (header_html, body_html) = html.split_once("<p>").unwrap_or((&html, ""));
+    let header = decode(header_html);
+    if !header.contains('|') {
+        return None;
+    }
+    let body = decode(&body_html.replace("<p>", " "));
+
+    let mut parts = header.split('|').map(str::trim).filter(|p| !p.is_empty());
+    let company = parts.next()?.to_string();
+
+    let mut workplace = Workplace::Unknown;
+    let mut fields = Vec::new();
+    for part in parts {
+        let kind = classify_workplace(part);
+        if kind != Workplace::Unknown {
+            // "ONSITE or REMOTE": any remote option wins.
+            if workplace == Workplace::Unknown || kind == Workplace::Remote {
+                workplace = kind;
+            }
+            // Only drop pure markers; "Remote (US/EU)" still carries a location.
+            if part.len() <= config::HIRING_MARKER_MAX_LEN {
+                continue;
+            }
+        }
+        if part.contains("://") || part.starts_with("www.") {
+            continue;
+        }
+        fields.push(part.to_string());
+    }
+    let mut fields = fields.into_iter();
+
+    let haystack = normalize(&format!("{header} {body}"));
+    let header_norm = normalize(&header);
+    Some(Job {
+        company,
+        role: fields.next(),
+        location: fields.next(),
+        stack: fields.next(),
+        workplace,
+        summary: excerpt(&body, config::HIRING_SUMMARY_CHARS),
+        url: format!("https://news.ycombinator.com/item?id={}", item.id),
+        posted: item.time,
+        regions: matching_labels(config::HIRING_LOCATIONS, &header_norm),
+        tech: matching_labels(config::HIRING_TECH, &haystack),
+    })
+}
+
+fn classify_workplace(part: &str) -> Workplace {
+    let p = part.to_lowercase();
+    if p.contains("remote") && !p.contains("no remote") && !p.contains("not remote") {
+        Workplace::Remote
+    } else if p.contains("hybrid") {
+        Workplace::Hybrid
+    } else if ["onsite", "on-site", "on site", "in office", "in-office"]
+        .iter()
+        .any(|m| p.contains(m))
+    {
+        Workplace::Onsite
+    } else {
+        Workplace::Unknown
+    }
+}
+
+/// Comment text is HTML with entities (`&#x2F;`) and inline links.
+fn decode(html: &str) -> String {
+    Html::parse_fragment(html).root_element().text().collect()
+}
+
+/// Lowercase, punctuation to spaces (except `+` and `#` for C++ / C#), padded so
+/// patterns can be matched as whole words with `" {pattern} "`.
+fn normalize(text: &str) -> String {
+    let words: String = text
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || c == '+' || c == '#' {
+                c.to_ascii_lowercase()
+            } else {
+                ' '
+            }
+        })
+        .collect();
+    format!(
+        " {} ",
+        words.split_whitespace().collect::<Vec<_>>().join(" ")
+    )
+}
+
+fn matching_labels(table: &[(&'static str, &[&str])], normalized: &str) -> Vec<&'static str> {
+    table
+        .iter()
+        .filter(|(_, patterns)| {
+            patterns
+                .iter()
+                .any(|p| normalized.contains(&format!(" {p} ")))
+        })
+        .map(|&(label, _)| label)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_header_fields_and_filters() {
+        let item: Item = serde_json::from_str(
+            r#"{
+                "by": "ferris", "id": 42, "parent": 1, "time": 1791000000, "type": "comment",
+                "text": "Crab Co | Senior Rust Engineer | Berlin, Germany | REMOTE (EU) or ONSITE | Rust, Postgres, Kubernetes<p>We build &quot;boring&quot; infra. Apply: <a href=\"https:&#x2F;&#x2F;crab.example&#x2F;jobs\">https:&#x2F;&#x2F;crab.example&#x2F;jobs</a>"
+            }"#,
+        )
+        .unwrap();
+        let job = parse_job(item).unwrap();
+
+        assert_eq!(job.company, "Crab Co");
+        assert_eq!(job.role.as_deref(), Some("Senior Rust Engineer"));
+        assert_eq!(job.location.as_deref(), Some("Berlin, Germany"));
+        assert_eq!(job.stack.as_deref(), Some("Rust, Postgres, Kubernetes"));
+        assert_eq!(job.workplace, Workplace::Remote);
+        assert!(job.summary.starts_with(r#"We build "boring" infra."#));
+        assert!(job.regions.contains(&"Europe"));
+        assert!(job.tech.contains(&"Rust"));
+        assert!(!job.tech.contains(&"Go"));
+
+        let reply: Item =
+            serde_json::from_str(r#"{ "id": 43, "time": 1, "text": "Is this still open?" }"#)
+                .unwrap();
+        assert!(parse_job(reply).is_none());
+    }
+}
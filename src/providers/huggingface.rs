@@ -0,0 +1,169 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+use url::Url;
+
+use super::FetchError;
+use crate::client::Client;
+use crate::config;
+
+/// Key: tab name from `HF_TABS` ("models", "datasets", "spaces").
+pub type HfTrending = HashMap<String, Vec<HfRepo>>;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HfRepo {
+    /// "owner/name".
+    pub id: String,
+    pub url: String,
+    pub likes: u64,
+    /// Last 30 days. Spaces have no download count.
+    pub downloads: Option<u64>,
+    /// Pipeline tag for models, first task category for datasets, SDK for spaces.
+    pub task: Option<String>,
+    /// Missing from some listings; the age is left out rather than shown as 1970.
+    pub last_modified: Option<DateTime<Utc>>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ApiRepo {
+    id: String,
+    #[serde(default)]
+    likes: u64,
+    #[serde(default)]
+    downloads: Option<u64>,
+    #[serde(default, rename = "pipeline_tag")]
+    pipeline_tag: Option<String>,
+    #[serde(default)]
+    tags: Vec<String>,
+    #[serde(default)]
+    sdk: Option<String>,
+    #[serde(default)]
+    last_modified: Option<DateTime<Utc>>,
+}
+
+/// The three listings are independent endpoints, so they are fetched concurrently.
+pub async fn fetch_trending(client: &Client) -> HfTrending {
+    info!("hugging face: fetching");
+
+    let futs = config::HF_TABS.iter().map(|&tab| async move {
+        let result = fetch_tab(client, tab).await;
+        (tab, result)
+    });
+
+    let mut feed = HfTrending::with_capacity(config::HF_TABS.len());
+    for (tab, result) in futures::future::join_all(futs).await {
+        match result {
+            Ok(repos) => {
+                feed.insert(tab.to_string(), repos);
+            }
+            Err(e) => warn!(tab, error = %e, "failed to fetch hugging face trending"),
+        }
+    }
+    feed
+}
+
+/// The list endpoints only return `lastModified` when asked for it, and asking
+/// for any field with `expand[]` drops the defaults, so every field is listed.
+async fn fetch_tab(client: &Client, tab: &str) -> Result<Vec<HfRepo>, FetchError> {
+    let limit = config::HF_PER_TAB.to_string();
+    let expand: &[&str] = match tab {
+        "models" => &["likes", "downloads", "pipeline_tag", "lastModified"],
+        "datasets" => &["likes", "downloads", "tags", "lastModified"],
+        _ => &["likes", "sdk", "lastModified"],
+    };
+    let params = [
+        ("sort", "trendingScore"),
+        ("direction", "-1"),
+        ("limit", limit.as_str()),
+    ]
+    .into_iter()
+    .chain(expand.iter().map(|&field| ("expand[]", field)));
+    let url = Url::parse_with_params(&format!("{}/{tab}", config::HF_API_BASE), params)?;
+
+    let repos: Vec<ApiRepo> = client
+        .get(url)
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+    Ok(repos.into_iter().map(|r| to_repo(r, tab)).collect())
+}
+
+fn to_repo(repo: ApiRepo, tab: &str) -> HfRepo {
+    // Model pages live at the root; datasets and spaces under their own prefix.
+    let url = match tab {
+        "models" => format!("https://huggingface.co/{}", repo.id),
+        _ => format!("https://huggingface.co/{tab}/{}", repo.id),
+    };
+    let task = repo
+        .pipeline_tag
+        .or_else(|| {
+            repo.tags
+                .iter()
+                .find_map(|t| t.strip_prefix("task_categories:"))
+                .map(str::to_string)
+        })
+        .or(repo.sdk);
+    HfRepo {
+        id: repo.id,
+        url,
+        likes: repo.likes,
+        downloads: repo.downloads,
+        task,
+        last_modified: repo.last_modified,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn listings_map_task_tags_and_urls() {
+        let models = r#"[{
+            "_id": "1", "id": "crab-lab/Crab-7B", "likes": 812, "downloads": 120345,
+            "pipeline_tag": "text-generation", "lastModified": "2026-10-17T08:00:00.000Z"
+        }]"#;
+        let datasets = r#"[{
+            "_id": "2", "id": "crab-lab/shells", "likes": 40, "downloads": 9000,
+            "tags": ["language:en", "task_categories:image-classification", "size_categories:1K<n<10K"],
+            "lastModified": "2026-10-16T08:00:00.000Z"
+        }]"#;
+        let spaces = r#"[{
+            "_id": "3", "id": "crab-lab/demo", "likes": 5, "sdk": "gradio",
+            "lastModified": "2026-10-15T08:00:00.000Z"
+        }]"#;
+
+        let parse = |body: &str, tab: &str| {
+            let repos: Vec<ApiRepo> = serde_json::from_str(body).unwrap();
+            repos.into_iter().map(|r| to_repo(r, tab)).next().unwrap()
+        };
+        let model = parse(models, "models");
+        assert_eq!(model.url, "https://huggingface.co/crab-lab/Crab-7B");
+        assert_eq!(model.task.as_deref(), Some("text-generation"));
+        assert_eq!(model.downloads, Some(120345));
+        assert_eq!(model.last_modified.unwrap().timestamp(), 1792224000);
+
+        let dataset = parse(datasets, "datasets");
+        assert_eq!(
+            dataset.url,
+            "https://huggingface.co/datasets/crab-lab/shells"
+        );
+        assert_eq!(dataset.task.as_deref(), Some("image-classification"));
+
+        let space = parse(spaces, "spaces");
+        assert_eq!(space.url, "https://huggingface.co/spaces/crab-lab/demo");
+        assert_eq!(space.task.as_deref(), Some("gradio"));
+        assert_eq!(space.downloads, None);
+
+        let undated = parse(
+            r#"[{"_id": "4", "id": "crab-lab/new", "likes": 0}]"#,
+            "models",
+        );
+        assert_eq!(undated.last_modified, None);
+    }
+}
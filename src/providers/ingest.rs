@@ -0,0 +1,131 @@
+use std::cmp::Reverse;
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::command::ExternalItem;
+use crate::config;
+
+/// Key: panel name from `INGEST_PANELS`. Newest first.
+pub type IngestFeed = HashMap<String, Vec<IngestedItem>>;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IngestedItem {
+    pub item: ExternalItem,
+    /// Epoch seconds. Expired items are pruned on the next push and skipped
+    /// when rendering, so they disappear within a minute either way.
+    pub expires_at: u64,
+}
+
+impl IngestedItem {
+    pub fn is_live(&self, now: u64) -> bool {
+        self.expires_at > now
+    }
+
+    /// A push replaces the item with the same `id`, else the same URL, else
+    /// the same title.
+    fn key(&self) -> &str {
+        let item = &self.item;
+        item.id
+            .as_deref()
+            .or(item.url.as_deref())
+            .unwrap_or(&item.title)
+    }
+}
+
+/// Body of `POST /ingest/{panel}`: `{"items": [...]}`, each item shaped like
+/// `ExternalItem` plus an optional `ttl_secs`. The README has a full example.
+#[derive(Deserialize)]
+pub struct IngestRequest {
+    pub items: Vec<PushedItem>,
+}
+
+#[derive(Clone, Deserialize)]
+pub struct PushedItem {
+    #[serde(flatten)]
+    item: ExternalItem,
+    /// Defaults to `INGEST_DEFAULT_TTL_SECS`, capped at `INGEST_MAX_TTL_SECS`.
+    /// Zero removes the item.
+    #[serde(default)]
+    ttl_secs: Option<u64>,
+}
+
+/// Returns `feed` with `pushed` upserted into `panel`. Items without a
+/// `timestamp` are stamped with `now`; expired items are pruned from every panel.
+pub fn upsert(feed: &IngestFeed, panel: &str, pushed: Vec<PushedItem>, now: u64) -> IngestFeed {
+    let mut feed: IngestFeed = feed
+        .iter()
+        .map(|(name, items)| {
+            let live = items.iter().filter(|i| i.is_live(now)).cloned().collect();
+            (name.clone(), live)
+        })
+        .collect();
+
+    let items = feed.entry(panel.to_string()).or_default();
+    for PushedItem { mut item, ttl_secs } in pushed {
+        item.timestamp.get_or_insert(now);
+        let ttl = ttl_secs
+            .unwrap_or(config::INGEST_DEFAULT_TTL_SECS)
+            .min(config::INGEST_MAX_TTL_SECS);
+        let new = IngestedItem {
+            item,
+            expires_at: now + ttl,
+        };
+        match items.iter_mut().find(|i| i.key() == new.key()) {
+            Some(existing) => *existing = new,
+            None => items.push(new),
+        }
+    }
+    items.retain(|i| i.is_live(now));
+    items.sort_by_key(|i| Reverse(i.item.timestamp));
+    items.truncate(config::INGEST_ITEMS_LIMIT);
+    feed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOW: u64 = 1792314000;
+
+    fn push(feed: &IngestFeed, body: &str, now: u64) -> IngestFeed {
+        let request: IngestRequest = serde_json::from_str(body).unwrap();
+        upsert(feed, "deploys", request.items, now)
+    }
+
+    #[test]
+    fn upserts_by_id_and_expires_items() {
+        let feed = push(
+            &IngestFeed::new(),
+            r#"{"items": [
+                {"id": "api", "title": "api: deploying v41", "timestamp": 1792313000},
+                {"title": "web: deployed v17", "url": "https://ci.example.com/web/17", "ttl_secs": 60}
+            ]}"#,
+            NOW,
+        );
+        let items = &feed["deploys"];
+        assert_eq!(items.len(), 2);
+        // Stamped with the push time, so it sorts first.
+        assert_eq!(items[0].item.timestamp, Some(NOW));
+        assert_eq!(items[0].expires_at, NOW + 60);
+        assert_eq!(items[1].expires_at, NOW + config::INGEST_DEFAULT_TTL_SECS);
+
+        let feed = push(
+            &feed,
+            r#"{"items": [{"id": "api", "title": "api: deployed v41", "author": "deploy-bot"}]}"#,
+            NOW + 120,
+        );
+        let items = &feed["deploys"];
+        // Same id replaced in place; the 60s item has expired.
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].item.title, "api: deployed v41");
+        assert_eq!(items[0].item.author.as_deref(), Some("deploy-bot"));
+
+        let feed = push(
+            &feed,
+            r#"{"items": [{"id": "api", "title": "api: deployed v41", "ttl_secs": 0}]}"#,
+            NOW + 180,
+        );
+        assert!(feed["deploys"].is_empty());
+    }
+}
@@ -0,0 +1,222 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, NaiveDateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+use url::Url;
+
+use super::{FetchError, jittered_delay};
+use crate::client::Client;
+use crate::config;
+
+/// Key: community (`name@instance`, lowercase) or "all" for the merged view.
+pub type LemmyFeed = HashMap<String, Vec<LemmyPost>>;
+
+/// Shaped like `RedditPost` so the panel reads the same.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LemmyPost {
+    pub title: String,
+    /// Linked URL, or the post page for text posts.
+    pub url: String,
+    /// Post page on the community's home instance.
+    pub post_url: String,
+    pub community: String,
+    pub score: i64,
+    pub comment_count: u64,
+    pub author: String,
+    pub created_at: DateTime<Utc>,
+    pub domain: String,
+    pub is_self: bool,
+}
+
+#[derive(Deserialize)]
+struct PostList {
+    posts: Vec<PostView>,
+}
+
+#[derive(Deserialize)]
+struct PostView {
+    post: Post,
+    creator: Person,
+    counts: Counts,
+}
+
+#[derive(Deserialize)]
+struct Post {
+    id: u64,
+    name: String,
+    #[serde(default)]
+    url: Option<String>,
+    published: String,
+    #[serde(default)]
+    deleted: bool,
+    #[serde(default)]
+    removed: bool,
+    /// Pinned in the community or instance-wide: Lemmy's stickied posts.
+    #[serde(default)]
+    featured_community: bool,
+    #[serde(default)]
+    featured_local: bool,
+}
+
+#[derive(Deserialize)]
+struct Person {
+    name: String,
+}
+
+#[derive(Deserialize)]
+struct Counts {
+    score: i64,
+    comments: u64,
+}
+
+/// Returns a feed keyed by community + an "all" entry with the merged top-N of
+/// every community, sorted by score like Reddit's "all".
+pub async fn fetch_lemmy_feed(client: &Client) -> LemmyFeed {
+    info!(
+        communities = config::LEMMY_COMMUNITIES.len(),
+        "lemmy: fetching"
+    );
+
+    let mut results: Vec<(&str, Vec<LemmyPost>)> =
+        Vec::with_capacity(config::LEMMY_COMMUNITIES.len());
+    for (i, &community) in config::LEMMY_COMMUNITIES.iter().enumerate() {
+        if i > 0 {
+            jittered_delay(
+                config::LEMMY_REQUEST_INTERVAL_MS,
+                config::LEMMY_REQUEST_JITTER_MS,
+            )
+            .await;
+        }
+        let posts = fetch_community(client, community)
+            .await
+            .unwrap_or_else(|e| {
+                warn!(community, error = %e, "failed to fetch lemmy community");
+                Vec::new()
+            });
+        results.push((community, posts));
+    }
+
+    let mut feed = LemmyFeed::with_capacity(results.len() + 1);
+
+    // Merged "all" view: top N across communities, sorted by score.
+    let mut all: Vec<LemmyPost> = results
+        .iter()
+        .flat_map(|(_, posts)| posts.iter().cloned())
+        .collect();
+    all.sort_by_key(|p| std::cmp::Reverse(p.score));
+    all.truncate(config::LEMMY_ALL_VIEW_LIMIT);
+    feed.insert(config::FILTER_ALL.to_string(), all);
+
+    for (community, posts) in results {
+        feed.insert(community.to_lowercase(), posts);
+    }
+
+    feed
+}
+
+async fn fetch_community(client: &Client, community: &str) -> Result<Vec<LemmyPost>, FetchError> {
+    // Ask the community's home instance: it has the complete, freshest listing.
+    let (name, instance) = community.split_once('@').unwrap_or((community, "lemmy.ml"));
+    let url = format!(
+        // +5 margin for pinned/removed posts filtered below.
+        "https://{instance}/api/v3/post/list?community_name={name}&sort={}&limit={}",
+        config::LEMMY_SORT,
+        config::LEMMY_POSTS_PER_COMMUNITY + 5
+    );
+
+    let list: PostList = client
+        .get(&url)
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+
+    Ok(parse_posts(list, community, instance))
+}
+
+fn parse_posts(list: PostList, community: &str, instance: &str) -> Vec<LemmyPost> {
+    list.posts
+        .into_iter()
+        .filter(|v| {
+            let p = &v.post;
+            !(p.deleted || p.removed || p.featured_community || p.featured_local)
+        })
+        .take(config::LEMMY_POSTS_PER_COMMUNITY)
+        .map(|v| {
+            let post_url = format!("https://{instance}/post/{}", v.post.id);
+            let link = v.post.url.filter(|u| !u.is_empty());
+            let domain = link
+                .as_deref()
+                .and_then(|u| Url::parse(u).ok())
+                .and_then(|u| {
+                    u.host_str()
+                        .map(|h| h.trim_start_matches("www.").to_string())
+                });
+            LemmyPost {
+                title: v.post.name,
+                is_self: domain.is_none(),
+                domain: domain.unwrap_or_else(|| format!("self.{community}")),
+                url: link.unwrap_or_else(|| post_url.clone()),
+                post_url,
+                community: community.to_lowercase(),
+                score: v.counts.score,
+                comment_count: v.counts.comments,
+                author: v.creator.name,
+                created_at: parse_published(&v.post.published),
+            }
+        })
+        .collect()
+}
+
+/// Lemmy ≥0.19 sends RFC 3339; older instances send a naive UTC timestamp.
+fn parse_published(s: &str) -> DateTime<Utc> {
+    DateTime::parse_from_rfc3339(s)
+        .map(|d| d.with_timezone(&Utc))
+        .or_else(|_| NaiveDateTime::parse_from_str(s, "%Y-%m-%dT%H:%M:%S%.f").map(|d| d.and_utc()))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn post_list_maps_to_reddit_like_posts() {
+        let body = r#"{ "posts": [
+            {
+                "post": { "id": 1, "name": "Pinned rules", "published": "2026-01-01T00:00:00Z",
+                          "featured_community": true, "deleted": false, "removed": false },
+                "creator": { "name": "mod" },
+                "community": { "name": "rust" },
+                "counts": { "score": 900, "comments": 0 }
+            },
+            {
+                "post": { "id": 2, "name": "Rust 1.99 released", "url": "https://blog.rust-lang.org/x",
+                          "published": "2026-10-18T08:00:00.123456Z" },
+                "creator": { "name": "ferris" },
+                "community": { "name": "rust" },
+                "counts": { "score": 120, "comments": 14 }
+            },
+            {
+                "post": { "id": 3, "name": "Ask: lifetimes?", "url": null,
+                          "published": "2023-06-01T12:00:00.123456" },
+                "creator": { "name": "newbie" },
+                "community": { "name": "rust" },
+                "counts": { "score": 5, "comments": 3 }
+            }
+        ] }"#;
+        let list: PostList = serde_json::from_str(body).unwrap();
+        let posts = parse_posts(list, "rust@programming.dev", "programming.dev");
+
+        assert_eq!(posts.len(), 2, "pinned post should be dropped");
+        assert_eq!(posts[0].domain, "blog.rust-lang.org");
+        assert_eq!(posts[0].post_url, "https://programming.dev/post/2");
+        assert!(!posts[0].is_self);
+
+        assert!(posts[1].is_self);
+        assert_eq!(posts[1].url, "https://programming.dev/post/3");
+        assert_eq!(posts[1].created_at.timestamp(), 1685620800);
+    }
+}
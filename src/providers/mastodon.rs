@@ -0,0 +1,290 @@
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet};
+
+use chrono::{DateTime, Utc};
+use futures::stream::{self, StreamExt};
+use scraper::Html;
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use super::{FetchError, excerpt};
+use crate::client::Client;
+use crate::config::{self, MastodonSource};
+
+/// Key: source label (see [`source_label`]) or "all" for the merged view.
+pub type MastodonFeed = HashMap<String, Vec<MastodonPost>>;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MastodonPost {
+    /// Federated URI: identical on every instance, so it deduplicates across sources.
+    pub uri: String,
+    pub url: String,
+    /// `user@host` for remote accounts, `user` for local ones.
+    pub author: String,
+    pub text: String,
+    pub created_at: DateTime<Utc>,
+    pub boosts: u64,
+    pub favourites: u64,
+    pub replies: u64,
+}
+
+impl MastodonPost {
+    pub fn score(&self) -> u64 {
+        self.boosts + self.favourites
+    }
+}
+
+#[derive(Deserialize)]
+struct Status {
+    uri: String,
+    #[serde(default)]
+    url: Option<String>,
+    created_at: DateTime<Utc>,
+    #[serde(default)]
+    content: String,
+    #[serde(default)]
+    spoiler_text: String,
+    #[serde(default)]
+    reblogs_count: u64,
+    #[serde(default)]
+    favourites_count: u64,
+    #[serde(default)]
+    replies_count: u64,
+    account: Account,
+    /// Set when the status is a boost; the boosted status carries the real content.
+    #[serde(default)]
+    reblog: Option<Box<Status>>,
+}
+
+#[derive(Deserialize)]
+struct Account {
+    #[serde(default)]
+    id: String,
+    acct: String,
+}
+
+impl From<Status> for MastodonPost {
+    fn from(status: Status) -> Self {
+        let status = match status.reblog {
+            Some(inner) => *inner,
+            None => status,
+        };
+        // Content warnings hide the body on Mastodon itself; show only the warning here.
+        let text = if status.spoiler_text.is_empty() {
+            html_to_text(&status.content)
+        } else {
+            format!("CW: {}", status.spoiler_text)
+        };
+        Self {
+            url: status.url.unwrap_or_else(|| status.uri.clone()),
+            uri: status.uri,
+            author: status.account.acct,
+            text,
+            created_at: status.created_at,
+            boosts: status.reblogs_count,
+            favourites: status.favourites_count,
+            replies: status.replies_count,
+        }
+    }
+}
+
+/// Display label and feed key for a configured source, e.g. `#rust@fosstodon.org`.
+pub fn source_label(instance: &str, source: &MastodonSource) -> String {
+    let host = instance
+        .trim_start_matches("https://")
+        .trim_start_matches("http://");
+    match *source {
+        MastodonSource::Tag(tag) => format!("#{tag}@{host}"),
+        MastodonSource::Account(user) => format!("@{user}@{host}"),
+    }
+}
+
+/// Fetch every configured source concurrently (they are spread over instances),
+/// then rank each by boosts + favourites within `MASTODON_WINDOW_SECS`.
+pub async fn fetch_mastodon_feed(client: &Client) -> MastodonFeed {
+    tracing::info!(
+        sources = config::MASTODON_SOURCES.len(),
+        "mastodon: fetching"
+    );
+
+    // Indices rather than `&(&str, MastodonSource)` keep the stream closure free
+    // of borrows, as in `feeds::fetch_all_feeds`.
+    let fetched: Vec<_> = stream::iter(0..config::MASTODON_SOURCES.len())
+        .map(|i| {
+            let client = client.clone();
+            async move {
+                let (instance, ref source) = config::MASTODON_SOURCES[i];
+                let posts = fetch_source(&client, instance, source)
+                    .await
+                    .unwrap_or_else(|e| {
+                        let source = source_label(instance, source);
+                        warn!(source, error = %e, "failed to fetch mastodon source");
+                        Vec::new()
+                    });
+                (i, posts)
+            }
+        })
+        .buffer_unordered(config::MASTODON_CONCURRENT_FETCHES)
+        .collect()
+        .await;
+
+    let since = Utc::now() - chrono::Duration::seconds(config::MASTODON_WINDOW_SECS as i64);
+    let mut feed = MastodonFeed::with_capacity(fetched.len() + 1);
+    let mut all = Vec::new();
+    for (i, posts) in fetched {
+        let (instance, ref source) = config::MASTODON_SOURCES[i];
+        all.extend(posts.iter().cloned());
+        feed.insert(
+            source_label(instance, source),
+            rank(posts, since, config::MASTODON_POSTS_PER_SOURCE),
+        );
+    }
+
+    let mut seen = HashSet::new();
+    all.retain(|p| seen.insert(p.uri.clone()));
+    feed.insert(
+        config::FILTER_ALL.to_string(),
+        rank(all, since, config::MASTODON_ALL_VIEW_LIMIT),
+    );
+    feed
+}
+
+/// Drops posts older than `since` and sorts the rest by boosts + favourites.
+fn rank(mut posts: Vec<MastodonPost>, since: DateTime<Utc>, limit: usize) -> Vec<MastodonPost> {
+    posts.retain(|p| p.created_at >= since);
+    posts.sort_by_key(|p| Reverse(p.score()));
+    posts.truncate(limit);
+    posts
+}
+
+async fn fetch_source(
+    client: &Client,
+    instance: &str,
+    source: &MastodonSource,
+) -> Result<Vec<MastodonPost>, FetchError> {
+    let limit = config::MASTODON_STATUSES_PER_SOURCE;
+    let url = match *source {
+        MastodonSource::Tag(tag) => {
+            format!("{instance}/api/v1/timelines/tag/{tag}?limit={limit}")
+        }
+        MastodonSource::Account(user) => {
+            // Statuses are addressed by the instance-local account id, not the name.
+            let account: Account = client
+                .get(format!("{instance}/api/v1/accounts/lookup?acct={user}"))
+                .send()
+                .await?
+                .error_for_status()?
+                .json()
+                .await?;
+            format!(
+                "{instance}/api/v1/accounts/{}/statuses?limit={limit}&exclude_replies=true",
+                account.id
+            )
+        }
+    };
+
+    let statuses: Vec<Status> = client
+        .get(&url)
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+
+    // spawn_blocking: HTML-to-text over 40 statuses is CPU-bound.
+    let posts =
+        tokio::task::spawn_blocking(move || statuses.into_iter().map(MastodonPost::from).collect())
+            .await?;
+    Ok(posts)
+}
+
+/// Status bodies are sanitized HTML paragraphs; flatten them into one excerpt.
+fn html_to_text(content: &str) -> String {
+    let spaced = content.replace("</p><p>", "</p> <p>").replace("<br>", " ");
+    let fragment = Html::parse_fragment(&spaced);
+    let text = fragment.root_element().text().collect::<String>();
+    excerpt(&text, config::MASTODON_EXCERPT_CHARS)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::providers::tests::test_client;
+    use axum::Router;
+    use axum::routing::get;
+
+    fn status(uri: &str, age_secs: i64, boosts: u64, favourites: u64) -> serde_json::Value {
+        let created_at = Utc::now() - chrono::Duration::seconds(age_secs);
+        serde_json::json!({
+            "id": "1",
+            "uri": uri,
+            "url": format!("{uri}/web"),
+            "created_at": created_at.to_rfc3339(),
+            "content": "<p>Rust 2.0 is <a href=\"#\">out</a></p><p>not really</p>",
+            "spoiler_text": "",
+            "reblogs_count": boosts,
+            "favourites_count": favourites,
+            "replies_count": 1,
+            "account": { "id": "42", "acct": "ferris@rustacean.social" },
+            "reblog": null
+        })
+    }
+
+    /// Serves the three endpoints the provider uses from a local port.
+    async fn stand_in_instance() -> String {
+        let tag = serde_json::json!([
+            status("https://a.example/1", 3600, 1, 2),
+            status("https://a.example/2", 60, 10, 20),
+            // Outside the ranking window.
+            status("https://a.example/3", 30 * 86400, 500, 500),
+        ]);
+        let mut boost = status("https://b.example/9", 7200, 0, 0);
+        boost["reblog"] = status("https://a.example/2", 60, 10, 20);
+
+        let app = Router::new()
+            .route(
+                "/api/v1/timelines/tag/{tag}",
+                get(move || async move { axum::Json(tag) }),
+            )
+            .route(
+                "/api/v1/accounts/lookup",
+                get(|| async {
+                    axum::Json(serde_json::json!({ "id": "42", "acct": "nixos_org" }))
+                }),
+            )
+            .route(
+                "/api/v1/accounts/42/statuses",
+                get(move || async move { axum::Json(serde_json::json!([boost])) }),
+            );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+        base
+    }
+
+    #[tokio::test]
+    async fn ranks_tag_and_account_statuses_from_instance() {
+        let client = test_client();
+        let base = stand_in_instance().await;
+
+        let tag = fetch_source(&client, &base, &MastodonSource::Tag("rust"))
+            .await
+            .unwrap();
+        let since = Utc::now() - chrono::Duration::seconds(config::MASTODON_WINDOW_SECS as i64);
+        let ranked = rank(tag, since, 10);
+        assert_eq!(ranked.len(), 2, "old post should fall outside the window");
+        assert_eq!(ranked[0].uri, "https://a.example/2");
+        assert_eq!(ranked[0].score(), 30);
+        assert_eq!(ranked[0].text, "Rust 2.0 is out not really");
+
+        // Boosts are unwrapped to the original status.
+        let account = fetch_source(&client, &base, &MastodonSource::Account("nixos_org"))
+            .await
+            .unwrap();
+        assert_eq!(account[0].uri, "https://a.example/2");
+        assert_eq!(
+            source_label(&base, &MastodonSource::Account("nixos_org")),
+            format!("@nixos_org@{}", base.trim_start_matches("http://"))
+        );
+    }
+}
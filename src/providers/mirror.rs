@@ -0,0 +1,118 @@
+use reqwest::StatusCode;
+use reqwest::header::{ETAG, IF_NONE_MATCH};
+
+use super::FetchError;
+use crate::cache::DataSnapshot;
+use crate::client::Client;
+
+/// A snapshot pulled from an upstream's `/api/data`.
+pub struct Mirrored {
+    pub snapshot: DataSnapshot,
+    /// Sent back as `If-None-Match` on the next pull.
+    pub etag: Option<String>,
+}
+
+/// Returns `None` when the upstream answers 304 to `etag`.
+pub async fn fetch_snapshot(
+    client: &Client,
+    url: &str,
+    token: Option<&str>,
+    etag: Option<&str>,
+) -> Result<Option<Mirrored>, FetchError> {
+    let mut request = client.get(url);
+    if let Some(token) = token {
+        request = request.bearer_auth(token);
+    }
+    if let Some(etag) = etag {
+        request = request.header(IF_NONE_MATCH, etag);
+    }
+    let response = request.send().await?;
+    if response.status() == StatusCode::NOT_MODIFIED {
+        return Ok(None);
+    }
+    let response = response.error_for_status()?;
+    let etag = response
+        .headers()
+        .get(ETAG)
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_string);
+    let body = response.bytes().await?;
+
+    // spawn_blocking: a full snapshot is a few MB of JSON.
+    let snapshot =
+        tokio::task::spawn_blocking(move || serde_json::from_slice::<DataSnapshot>(&body))
+            .await??;
+    Ok(Some(Mirrored { snapshot, etag }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cache;
+    use crate::providers::tests::test_client;
+    use axum::Router;
+    use axum::http::HeaderMap;
+    use axum::response::IntoResponse;
+    use axum::routing::get;
+
+    /// An upstream snapshot with a story and a tuple-keyed map; the cache tests
+    /// round-trip every other field.
+    fn upstream_snapshot() -> serde_json::Value {
+        let mut value = serde_json::to_value(&**cache::new_shared_data().load()).unwrap();
+        value["hn_pages"]["top"] = serde_json::json!([{
+            "id": 42, "title": "Show HN: tty1", "url": "https://example.com/",
+            "hn_url": "https://news.ycombinator.com/item?id=42", "score": 120,
+            "author": "pg", "created_at": 1792314000, "comment_count": 31,
+            "domain": "example.com"
+        }]);
+        value["hiring_feed"] = serde_json::json!([[[1792314000, "all"], [{
+            "company": "Ferrous", "role": "Backend engineer", "location": "Berlin",
+            "workplace": "Hybrid", "stack": "Rust", "summary": "Compilers.",
+            "url": "https://news.ycombinator.com/item?id=43", "posted": 1792314000,
+            "regions": [], "tech": ["Rust"]
+        }]]]);
+        value["last_fetched"] = serde_json::json!("2026-10-18T09:00:00Z");
+        value
+    }
+
+    async fn stand_in_upstream(body: serde_json::Value) -> String {
+        let app = Router::new().route(
+            "/api/data",
+            get(move |headers: HeaderMap| async move {
+                if headers["authorization"] != "Bearer shared" {
+                    return StatusCode::UNAUTHORIZED.into_response();
+                }
+                if headers.get("if-none-match").is_some_and(|v| v == "\"v1\"") {
+                    return StatusCode::NOT_MODIFIED.into_response();
+                }
+                ([(ETAG, "\"v1\"")], axum::Json(body)).into_response()
+            }),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/api/data", listener.local_addr().unwrap());
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+        url
+    }
+
+    #[tokio::test]
+    async fn pulls_snapshot_with_token_and_etag() {
+        let client = test_client();
+        let body = upstream_snapshot();
+        let url = stand_in_upstream(body.clone()).await;
+
+        let mirrored = fetch_snapshot(&client, &url, Some("shared"), None)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(mirrored.etag.as_deref(), Some("\"v1\""));
+        let story = &mirrored.snapshot.hn_pages["top"][0];
+        assert_eq!(story.author, "pg");
+        assert_eq!(story.comment_count, 31);
+        assert_eq!(serde_json::to_value(&mirrored.snapshot).unwrap(), body);
+
+        let unchanged = fetch_snapshot(&client, &url, Some("shared"), Some("\"v1\"")).await;
+        assert!(unchanged.unwrap().is_none());
+
+        assert!(fetch_snapshot(&client, &url, None, None).await.is_err());
+    }
+}
@@ -1,6 +1,32 @@
+pub mod advisories;
+pub mod arxiv;
+pub mod bluesky;
+pub mod command;
+pub mod crates;
+pub mod discourse;
+pub mod feeds;
+pub mod forgejo;
 pub mod github;
 pub mod hackernews;
+pub mod hiring;
+pub mod huggingface;
+pub mod ingest;
+pub mod lemmy;
+pub mod mastodon;
+pub mod mirror;
+pub mod producthunt;
 pub mod reddit;
+pub mod releases;
+pub mod stackexchange;
+pub mod status;
+pub mod tildes;
+
+use std::sync::{Mutex, PoisonError};
+use std::time::Duration;
+
+use tokio::time::Instant;
+
+use crate::config;
 
 #[derive(Debug, thiserror::Error)]
 pub enum FetchError {
@@ -8,6 +34,14 @@ pub enum FetchError {
     Http(#[from] reqwest_middleware::Error),
     #[error("background task panicked: {0}")]
     TaskPanic(#[from] tokio::task::JoinError),
+    #[error("invalid JSON: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("invalid XML: {0}")]
+    Xml(#[from] roxmltree::Error),
+    #[error("invalid URL: {0}")]
+    Url(#[from] url::ParseError),
+    #[error("API error: {0}")]
+    Api(String),
 }
 
 /// Wrap raw reqwest errors through the middleware error type so `?` works on
@@ -22,9 +56,46 @@ impl From<reqwest::Error> for FetchError {
 /// requests: a varying gap looks less robotic than a fixed interval. `jitter_ms`
 /// is clamped to `base_ms` so the delay can never go negative.
 pub(crate) async fn jittered_delay(base_ms: u64, jitter_ms: u64) {
+    tokio::time::sleep(jittered(base_ms, jitter_ms)).await;
+}
+
+fn jittered(base_ms: u64, jitter_ms: u64) -> Duration {
     let jitter_ms = jitter_ms.min(base_ms);
-    let millis = base_ms - jitter_ms + fastrand::u64(0..=2 * jitter_ms);
-    tokio::time::sleep(std::time::Duration::from_millis(millis)).await;
+    Duration::from_millis(base_ms - jitter_ms + fastrand::u64(0..=2 * jitter_ms))
+}
+
+/// Start of the latest GitHub request slot handed out. Trending, releases and
+/// advisories run concurrently, so they book slots on this one schedule to keep
+/// github.com at a single `GITHUB_REQUEST_INTERVAL_MS` cadence between them.
+static GITHUB_LAST_SLOT: Mutex<Option<Instant>> = Mutex::new(None);
+
+/// Waits for the next GitHub request slot: a jittered interval after the
+/// previous one, whichever provider took it. Immediate when GitHub has been idle.
+pub(crate) async fn github_pace() {
+    let slot = {
+        let mut last = GITHUB_LAST_SLOT
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        let now = Instant::now();
+        let gap = jittered(
+            config::GITHUB_REQUEST_INTERVAL_MS,
+            config::GITHUB_REQUEST_JITTER_MS,
+        );
+        let slot = last.map_or(now, |prev| (prev + gap).max(now));
+        *last = Some(slot);
+        slot
+    };
+    tokio::time::sleep_until(slot).await;
+}
+
+/// Collapses whitespace and cuts to `max_chars`, ending with an ellipsis when cut.
+/// Shared by the providers that show post bodies instead of titles.
+pub(crate) fn excerpt(text: &str, max_chars: usize) -> String {
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    match text.char_indices().nth(max_chars) {
+        Some((cut, _)) => format!("{}…", text[..cut].trim_end()),
+        None => text,
+    }
 }
 
 /// Collected, trimmed text content of an element. Shared by the HTML scrapers.
@@ -34,7 +105,21 @@ pub(crate) fn text(el: scraper::ElementRef) -> String {
 
 #[cfg(test)]
 pub(crate) mod tests {
+    use super::*;
+
     pub fn test_client() -> crate::client::Client {
         crate::client::build_client()
     }
+
+    #[tokio::test]
+    async fn github_pace_spaces_concurrent_providers() {
+        let start = Instant::now();
+        let paced = || async {
+            github_pace().await;
+            github_pace().await;
+        };
+        tokio::join!(paced(), paced());
+        let min_gap = config::GITHUB_REQUEST_INTERVAL_MS - config::GITHUB_REQUEST_JITTER_MS;
+        assert!(start.elapsed() >= Duration::from_millis(3 * min_gap));
+    }
 }
@@ -0,0 +1,231 @@
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet};
+
+use chrono::{DateTime, Duration, Utc};
+use reqwest::header::CONTENT_TYPE;
+use serde::{Deserialize, Serialize};
+use tracing::{debug, info, warn};
+
+use super::FetchError;
+use crate::client::Client;
+use crate::config;
+
+/// Key: (tab, topic label) e.g. ("today", "developer tools"), plus
+/// (tab, "all") for every topic merged.
+pub type ProductHuntFeed = HashMap<(String, String), Vec<ProductLaunch>>;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProductLaunch {
+    pub id: String,
+    pub name: String,
+    pub tagline: String,
+    /// Launch page on producthunt.com, where the comments are.
+    pub url: String,
+    pub votes: u64,
+    pub comment_count: u64,
+    pub created_at: DateTime<Utc>,
+}
+
+const POSTS_QUERY: &str = "query Posts($topic: String!, $postedAfter: DateTime!, $first: Int!) {
+  posts(topic: $topic, postedAfter: $postedAfter, order: VOTES, first: $first) {
+    edges { node { id name tagline url votesCount commentsCount createdAt } }
+  }
+}";
+
+#[derive(Deserialize)]
+struct Response {
+    #[serde(default)]
+    data: Option<Data>,
+    #[serde(default)]
+    errors: Vec<GraphQlError>,
+}
+
+#[derive(Deserialize)]
+struct GraphQlError {
+    message: String,
+}
+
+#[derive(Deserialize)]
+struct Data {
+    posts: Connection,
+}
+
+#[derive(Deserialize)]
+struct Connection {
+    edges: Vec<Edge>,
+}
+
+#[derive(Deserialize)]
+struct Edge {
+    node: Post,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Post {
+    id: String,
+    name: String,
+    #[serde(default)]
+    tagline: String,
+    url: String,
+    #[serde(default)]
+    votes_count: u64,
+    #[serde(default)]
+    comments_count: u64,
+    created_at: DateTime<Utc>,
+}
+
+/// The GraphQL API has no anonymous access; without `PRODUCTHUNT_TOKEN` the
+/// panel simply stays empty.
+pub async fn fetch_producthunt_feed(client: &Client) -> ProductHuntFeed {
+    let Some(token) = config::producthunt_token() else {
+        debug!("product hunt: no PRODUCTHUNT_TOKEN, skipping");
+        return ProductHuntFeed::new();
+    };
+    info!(
+        topics = config::PRODUCTHUNT_TOPICS.len(),
+        "product hunt: fetching"
+    );
+    fetch_feed(client, config::PRODUCTHUNT_API_URL, &token).await
+}
+
+async fn fetch_feed(client: &Client, api_url: &str, token: &str) -> ProductHuntFeed {
+    let now = Utc::now();
+    let mut feed = ProductHuntFeed::with_capacity(
+        config::PRODUCTHUNT_TABS.len() * (config::PRODUCTHUNT_TOPICS.len() + 1),
+    );
+    for &(tab, window_hours) in config::PRODUCTHUNT_TABS {
+        let posted_after = now - Duration::hours(window_hours);
+        let mut all = Vec::new();
+        for &(label, slug) in config::PRODUCTHUNT_TOPICS {
+            match fetch_posts(client, api_url, token, slug, posted_after).await {
+                Ok(launches) => {
+                    all.extend(launches.iter().cloned());
+                    feed.insert((tab.to_string(), label.to_string()), launches);
+                }
+                Err(e) => warn!(tab, topic = label, error = %e, "failed to fetch product hunt"),
+            }
+        }
+        // A launch is usually tagged with several topics.
+        let mut seen = HashSet::new();
+        all.retain(|l| seen.insert(l.id.clone()));
+        all.sort_by_key(|l| Reverse(l.votes));
+        all.truncate(config::PRODUCTHUNT_POSTS_PER_TOPIC);
+        feed.insert((tab.to_string(), config::FILTER_ALL.to_string()), all);
+    }
+    feed
+}
+
+async fn fetch_posts(
+    client: &Client,
+    api_url: &str,
+    token: &str,
+    topic: &str,
+    posted_after: DateTime<Utc>,
+) -> Result<Vec<ProductLaunch>, FetchError> {
+    let body = serde_json::json!({
+        "query": POSTS_QUERY,
+        "variables": {
+            "topic": topic,
+            "postedAfter": posted_after.to_rfc3339(),
+            "first": config::PRODUCTHUNT_POSTS_PER_TOPIC,
+        },
+    });
+    let response: Response = client
+        .post(api_url)
+        .bearer_auth(token)
+        .header(CONTENT_TYPE, "application/json")
+        .body(body.to_string())
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+
+    // GraphQL reports query errors (bad token, complexity limit) with a 200.
+    let Some(data) = response.data else {
+        let messages: Vec<String> = response.errors.into_iter().map(|e| e.message).collect();
+        return Err(FetchError::Api(messages.join("; ")));
+    };
+    Ok(data
+        .posts
+        .edges
+        .into_iter()
+        .map(|Edge { node }| ProductLaunch {
+            id: node.id,
+            name: node.name,
+            tagline: node.tagline,
+            url: node.url,
+            votes: node.votes_count,
+            comment_count: node.comments_count,
+            created_at: node.created_at,
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::providers::tests::test_client;
+    use axum::Router;
+    use axum::http::HeaderMap;
+    use axum::routing::post;
+
+    fn node(id: &str, votes: u64) -> serde_json::Value {
+        serde_json::json!({ "node": {
+            "id": id, "name": format!("Launch {id}"), "tagline": "Ship it",
+            "url": format!("https://www.producthunt.com/posts/launch-{id}"),
+            "votesCount": votes, "commentsCount": 4,
+            "createdAt": "2026-10-18T07:01:00Z"
+        } })
+    }
+
+    /// Answers every query with the same two launches, or a GraphQL error
+    /// when the bearer token is wrong.
+    async fn stand_in_api() -> String {
+        let app = Router::new().route(
+            "/v2/api/graphql",
+            post(
+                |headers: HeaderMap, axum::Json(body): axum::Json<serde_json::Value>| async move {
+                    assert!(body["query"].as_str().unwrap().contains("posts("));
+                    assert!(body["variables"]["topic"].is_string());
+                    if headers["authorization"] != "Bearer dev-token" {
+                        return axum::Json(serde_json::json!({
+                            "data": null,
+                            "errors": [{ "message": "invalid_oauth_token" }]
+                        }));
+                    }
+                    axum::Json(serde_json::json!({
+                        "data": { "posts": { "edges": [node("1", 120), node("2", 80)] } }
+                    }))
+                },
+            ),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base = format!("http://{}/v2/api/graphql", listener.local_addr().unwrap());
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+        base
+    }
+
+    #[tokio::test]
+    async fn fetches_topics_per_tab_and_merges_all() {
+        let client = test_client();
+        let api = stand_in_api().await;
+
+        let feed = fetch_feed(&client, &api, "dev-token").await;
+        let (label, _) = config::PRODUCTHUNT_TOPICS[0];
+        let topic = &feed[&("today".to_string(), label.to_string())];
+        assert_eq!(topic[0].name, "Launch 1");
+        assert_eq!(topic[0].votes, 120);
+        assert_eq!(topic[0].comment_count, 4);
+
+        // The same launches under every topic collapse to one entry each.
+        let all = &feed[&("week".to_string(), config::FILTER_ALL.to_string())];
+        assert_eq!(all.len(), 2);
+
+        let err = fetch_posts(&client, &api, "wrong", "developer-tools", Utc::now())
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("invalid_oauth_token"));
+    }
+}
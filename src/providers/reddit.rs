@@ -1,37 +1,95 @@
 use std::collections::HashMap;
+use std::future::Future;
 use std::sync::LazyLock;
 
 use crate::client::Client;
+use reqwest::header::{CONTENT_TYPE, USER_AGENT};
 use scraper::{Html, Selector};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use tracing::{info, warn};
 
-use super::{FetchError, jittered_delay, text};
+use super::{FetchError, feeds, jittered_delay, text};
 use crate::config;
 
-/// Key: subreddit name (lowercase) or "all" for the merged top-N view.
-pub type RedditFeed = HashMap<String, Vec<RedditPost>>;
+/// Key: (subreddit name (lowercase) or "all" for the merged top-N view, sort key).
+pub type RedditFeed = HashMap<(String, String), Vec<RedditPost>>;
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct RedditPost {
     pub title: String,
-    #[serde(default)]
     pub url: String,
     pub permalink: String,
     pub subreddit: String,
     pub score: i64,
-    #[serde(default)]
     pub author: String,
-    #[serde(rename = "created_utc")]
     pub created_at: f64,
-    #[serde(rename = "num_comments")]
     pub comment_count: u32,
-    #[serde(default)]
     pub domain: String,
-    #[serde(default)]
     pub is_self: bool,
-    #[serde(default, skip_serializing)]
-    pub stickied: bool,
+    /// Which link of the source chain served this post.
+    pub source: RedditSource,
+}
+
+/// A listing child's `data` as the Reddit API names its fields. `RedditPost`
+/// keeps ours, so the snapshot mirrors pull doesn't follow API renames.
+#[derive(Deserialize)]
+struct ApiPost {
+    title: String,
+    #[serde(default)]
+    url: String,
+    permalink: String,
+    subreddit: String,
+    score: i64,
+    #[serde(default)]
+    author: String,
+    created_utc: f64,
+    num_comments: u32,
+    #[serde(default)]
+    domain: String,
+    #[serde(default)]
+    is_self: bool,
+    #[serde(default)]
+    stickied: bool,
+}
+
+impl ApiPost {
+    fn into_post(self) -> RedditPost {
+        RedditPost {
+            title: self.title,
+            url: self.url,
+            permalink: self.permalink,
+            subreddit: self.subreddit,
+            score: self.score,
+            author: self.author,
+            created_at: self.created_utc,
+            comment_count: self.num_comments,
+            domain: self.domain,
+            is_self: self.is_self,
+            source: RedditSource::OAuth,
+        }
+        .finalize()
+    }
+}
+
+/// Fallback chain, in order of preference. OAuth carries full metrics but needs
+/// credentials; old.reddit is the keyless default; RSS survives most HTML blocks
+/// but has no score or comment counts.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RedditSource {
+    OAuth,
+    #[default]
+    OldReddit,
+    Rss,
+}
+
+impl RedditSource {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::OAuth => "oauth",
+            Self::OldReddit => "old.reddit",
+            Self::Rss => "rss",
+        }
+    }
 }
 
 impl RedditPost {
@@ -45,51 +103,249 @@ impl RedditPost {
     }
 }
 
-/// Returns a feed keyed by subreddit name + an "all" entry with the merged top-N.
+/// Sort keys fetched for a subreddit: its `REDDIT_SUB_SORTS` override, else the defaults.
+pub fn sorts_for(subreddit: &str) -> &'static [&'static str] {
+    config::REDDIT_SUB_SORTS
+        .iter()
+        .find(|&&(sub, _)| sub == subreddit)
+        .map_or(config::REDDIT_DEFAULT_SORTS, |&(_, sorts)| sorts)
+}
+
+/// Returns a feed keyed by (subreddit, sort) + an ("all", sort) entry per sort
+/// mode with the merged top-N of every subreddit fetched in that mode.
 pub async fn fetch_reddit_feed(client: &Client) -> RedditFeed {
     info!(
         subreddits = config::REDDIT_SUBREDDITS.len(),
         "reddit: fetching"
     );
 
+    let token = match config::reddit_oauth_credentials() {
+        Some((id, secret)) => fetch_oauth_token(client, &id, &secret)
+            .await
+            .map_err(|e| warn!(error = %e, "reddit: OAuth token request failed, skipping API"))
+            .ok(),
+        None => None,
+    };
+
     // Sequential, jitter-paced fetches: a randomized gap between requests so the
     // cadence does not look like a fixed-interval bot. No delay before the first.
-    let mut results: Vec<(&str, Vec<RedditPost>)> =
+    // Subreddits are fetched as multireddits (`/r/a+b+c/hot`) and split back by
+    // each post's subreddit, turning ~45 requests per cycle into ~15 plus refetches.
+    let mut results: Vec<(&str, &str, Vec<RedditPost>)> =
         Vec::with_capacity(config::REDDIT_SUBREDDITS.len());
-    for (i, &sub) in config::REDDIT_SUBREDDITS.iter().enumerate() {
+    let mut first = true;
+    for &(sort, _, _) in config::REDDIT_SORTS {
+        let (busy, quiet): (Vec<&str>, Vec<&str>) = config::REDDIT_SUBREDDITS
+            .iter()
+            .copied()
+            .filter(|sub| sorts_for(sub).contains(&sort))
+            .partition(|sub| config::REDDIT_BUSY_SUBREDDITS.contains(sub));
+        let batches = busy
+            .chunks(config::REDDIT_MULTI_BATCH_SIZE)
+            .chain(quiet.chunks(config::REDDIT_MULTI_BATCH_SIZE));
+        for batch in batches {
+            let token = token.as_deref();
+            let fetch = |subreddits: String, limit| async move {
+                fetch_with_fallback(client, token, &subreddits, sort, limit).await
+            };
+            for (sub, posts) in fetch_batch(batch, sort, &mut first, fetch).await {
+                results.push((sub, sort, posts));
+            }
+        }
+    }
+
+    // Which source served each listing, so a blocked link in the chain shows up
+    // as a shift in the counts rather than only as per-request warnings.
+    let served_by = |source: RedditSource| {
+        results
+            .iter()
+            .filter(|(_, _, posts)| posts.first().is_some_and(|p| p.source == source))
+            .count()
+    };
+    info!(
+        oauth = served_by(RedditSource::OAuth),
+        old_reddit = served_by(RedditSource::OldReddit),
+        rss = served_by(RedditSource::Rss),
+        "reddit: listings by source"
+    );
+
+    let mut feed = RedditFeed::with_capacity(results.len() + config::REDDIT_SORTS.len());
+
+    // Merged "all" view per sort mode: top N across subreddits, sorted by score.
+    for &(sort, _, _) in config::REDDIT_SORTS {
+        let mut all: Vec<RedditPost> = results
+            .iter()
+            .filter(|&&(_, s, _)| s == sort)
+            .flat_map(|(_, _, posts)| posts.iter().cloned())
+            .collect();
+        all.sort_by_key(|p| std::cmp::Reverse(p.score));
+        all.truncate(config::REDDIT_ALL_VIEW_LIMIT);
+        feed.insert((config::FILTER_ALL.to_string(), sort.to_string()), all);
+    }
+
+    for (sub, sort, posts) in results {
+        feed.insert((sub.to_lowercase(), sort.to_string()), posts);
+    }
+
+    feed
+}
+
+async fn pace(first: &mut bool) {
+    if !*first {
+        jittered_delay(
+            config::REDDIT_REQUEST_INTERVAL_MS,
+            config::REDDIT_REQUEST_JITTER_MS,
+        )
+        .await;
+    }
+    *first = false;
+}
+
+/// Fetches `batch` as one combined listing and splits it back per subreddit, in
+/// batch order. `fetch(subreddits, limit)` walks the source chain once; each
+/// call is paced. A subreddit is refetched alone only when the listing came back
+/// full and busier neighbours left it under `REDDIT_REFETCH_BELOW` posts. A
+/// listing that isn't full already holds every post the window has.
+async fn fetch_batch<'a, F, Fut>(
+    batch: &[&'a str],
+    sort: &str,
+    first: &mut bool,
+    mut fetch: F,
+) -> Vec<(&'a str, Vec<RedditPost>)>
+where
+    F: FnMut(String, usize) -> Fut,
+    Fut: Future<Output = Vec<RedditPost>>,
+{
+    pace(first).await;
+    let combined = fetch(batch.join("+"), config::REDDIT_MULTI_LIMIT).await;
+    let full = combined.len() >= config::REDDIT_MULTI_LIMIT;
+    let mut by_sub = split_by_subreddit(combined);
+
+    let mut split = Vec::with_capacity(batch.len());
+    for &sub in batch {
+        let mut posts = by_sub.remove(&sub.to_lowercase()).unwrap_or_default();
+        if full && posts.len() < config::REDDIT_REFETCH_BELOW {
+            pace(first).await;
+            tracing::debug!(
+                subreddit = sub,
+                sort,
+                got = posts.len(),
+                "reddit: crowded out, refetching"
+            );
+            let own = fetch(sub.to_string(), config::REDDIT_POSTS_PER_SUB).await;
+            // Keep the combined-listing rows if the dedicated fetch fails too.
+            if own.len() > posts.len() {
+                posts = own;
+            }
+        }
+        posts.truncate(config::REDDIT_POSTS_PER_SUB);
+        split.push((sub, posts));
+    }
+    split
+}
+
+/// Splits a combined listing into per-subreddit lists, keeping listing order.
+fn split_by_subreddit(posts: Vec<RedditPost>) -> HashMap<String, Vec<RedditPost>> {
+    let mut by_sub: HashMap<String, Vec<RedditPost>> = HashMap::new();
+    for post in posts {
+        by_sub.entry(post.subreddit.clone()).or_default().push(post);
+    }
+    by_sub
+}
+
+/// Walks the source chain until one returns posts. An empty listing counts as a
+/// failure: a block page parses to zero rows, and real listings are never empty.
+async fn fetch_with_fallback(
+    client: &Client,
+    token: Option<&str>,
+    subreddit: &str,
+    sort: &str,
+    limit: usize,
+) -> Vec<RedditPost> {
+    let chain = token
+        .map(Link::Api)
+        .into_iter()
+        .chain([Link::OldReddit, Link::Rss]);
+    for (i, link) in chain.enumerate() {
         if i > 0 {
+            // Fallbacks hit reddit.com again; keep the same pacing as the main loop.
             jittered_delay(
                 config::REDDIT_REQUEST_INTERVAL_MS,
                 config::REDDIT_REQUEST_JITTER_MS,
             )
             .await;
         }
-        let posts = scrape_subreddit(client, sub).await.unwrap_or_else(|e| {
-            warn!(subreddit = sub, error = %e, "failed to fetch subreddit");
-            Vec::new()
-        });
-        results.push((sub, posts));
+        let source = link.source();
+        let result = fetch_from(client, link, subreddit, sort, limit).await;
+        match result {
+            Ok(posts) if !posts.is_empty() => {
+                tracing::debug!(subreddit, sort, source = source.as_str(), "reddit: served");
+                return posts
+                    .into_iter()
+                    .map(|p| RedditPost { source, ..p })
+                    .collect();
+            }
+            Ok(_) => warn!(
+                subreddit,
+                sort,
+                source = source.as_str(),
+                "reddit: no posts, falling back"
+            ),
+            Err(e) => {
+                warn!(subreddit, sort, source = source.as_str(), error = %e, "reddit: fetch failed, falling back")
+            }
+        }
     }
+    warn!(
+        subreddit,
+        sort, "failed to fetch subreddit from every source"
+    );
+    Vec::new()
+}
 
-    let mut feed = RedditFeed::with_capacity(results.len() + 1);
+/// One link of the source chain. The API link carries its bearer token, so it
+/// only exists when a token was obtained.
+#[derive(Clone, Copy)]
+enum Link<'a> {
+    Api(&'a str),
+    OldReddit,
+    Rss,
+}
 
-    // Merged "all" view: top N across all subreddits, sorted by score.
-    let mut all: Vec<RedditPost> = results
-        .iter()
-        .flat_map(|(_, posts)| posts.iter().cloned())
-        .collect();
-    all.sort_by_key(|p| std::cmp::Reverse(p.score));
-    all.truncate(config::REDDIT_ALL_VIEW_LIMIT);
-    feed.insert(config::FILTER_ALL.to_string(), all);
+impl Link<'_> {
+    fn source(self) -> RedditSource {
+        match self {
+            Self::Api(_) => RedditSource::OAuth,
+            Self::OldReddit => RedditSource::OldReddit,
+            Self::Rss => RedditSource::Rss,
+        }
+    }
+}
 
-    for (sub, posts) in results {
-        feed.insert(sub.to_lowercase(), posts);
+async fn fetch_from(
+    client: &Client,
+    link: Link<'_>,
+    subreddit: &str,
+    sort: &str,
+    limit: usize,
+) -> Result<Vec<RedditPost>, FetchError> {
+    match link {
+        Link::Api(token) => fetch_oauth(client, token, subreddit, sort, limit).await,
+        Link::OldReddit => scrape_subreddit(client, subreddit, sort, limit).await,
+        Link::Rss => fetch_rss(client, subreddit, sort, limit).await,
     }
+}
 
-    feed
+/// Listing path and `t=` window for a sort key. Unknown keys are used as the
+/// listing path verbatim so a typo in `REDDIT_SUB_SORTS` still fetches something.
+fn listing(sort: &str) -> (&str, Option<&'static str>) {
+    config::REDDIT_SORTS
+        .iter()
+        .find(|&&(key, _, _)| key == sort)
+        .map_or((sort, None), |&(_, path, window)| (path, window))
 }
 
-// ===== Active source: old.reddit HTML =====
+// ===== Source 2: old.reddit HTML =====
 //
 // Reddit disabled the public JSON API (www.reddit.com/.../hot.json now 403s),
 // but old.reddit.com still serves the listing as HTML with score and comment
@@ -108,13 +364,29 @@ static SELECTORS: LazyLock<Option<RedditSelectors>> = LazyLock::new(|| {
     })
 });
 
-async fn scrape_subreddit(client: &Client, subreddit: &str) -> Result<Vec<RedditPost>, FetchError> {
-    let url = format!(
+fn listing_url(subreddit: &str, sort: &str, limit: usize) -> String {
+    let (path, window) = listing(sort);
+    let mut url = format!(
         // +5 margin for stickied/promoted/deleted rows filtered out during parsing.
-        "https://old.reddit.com/r/{}/hot/?limit={}",
+        "https://old.reddit.com/r/{}/{}/?limit={}",
         subreddit,
-        config::REDDIT_POSTS_PER_SUB + 5
+        path,
+        limit + 5
     );
+    if let Some(t) = window {
+        url.push_str("&t=");
+        url.push_str(t);
+    }
+    url
+}
+
+async fn scrape_subreddit(
+    client: &Client,
+    subreddit: &str,
+    sort: &str,
+    limit: usize,
+) -> Result<Vec<RedditPost>, FetchError> {
+    let url = listing_url(subreddit, sort, limit);
 
     let html = client
         .get(&url)
@@ -125,11 +397,11 @@ async fn scrape_subreddit(client: &Client, subreddit: &str) -> Result<Vec<Reddit
         .await?;
 
     // spawn_blocking: scraper HTML parsing is CPU-bound.
-    let posts = tokio::task::spawn_blocking(move || parse_listing_html(&html)).await?;
+    let posts = tokio::task::spawn_blocking(move || parse_listing_html(&html, limit)).await?;
     Ok(posts)
 }
 
-fn parse_listing_html(html: &str) -> Vec<RedditPost> {
+fn parse_listing_html(html: &str, limit: usize) -> Vec<RedditPost> {
     let Some(sel) = SELECTORS.as_ref() else {
         tracing::error!("reddit: CSS selectors failed to parse");
         return Vec::new();
@@ -146,7 +418,7 @@ fn parse_listing_html(html: &str) -> Vec<RedditPost> {
         return Vec::new();
     }
 
-    let mut posts = Vec::with_capacity(config::REDDIT_POSTS_PER_SUB);
+    let mut posts = Vec::with_capacity(limit);
     for thing in document.select(&sel.thing) {
         let el = thing.value();
 
@@ -199,12 +471,12 @@ fn parse_listing_html(html: &str) -> Vec<RedditPost> {
                 .unwrap_or(0),
             domain,
             is_self,
-            stickied: false,
+            source: RedditSource::OldReddit,
         }
         .finalize();
 
         posts.push(post);
-        if posts.len() >= config::REDDIT_POSTS_PER_SUB {
+        if posts.len() >= limit {
             break;
         }
     }
@@ -212,39 +484,148 @@ fn parse_listing_html(html: &str) -> Vec<RedditPost> {
     posts
 }
 
-// ===== Retained: public JSON API (disabled by Reddit) =====
+// ===== Source 1: OAuth API =====
 //
-// Reddit shut down the public .json endpoint (it now 403s), so this is unused
-// and `scrape_subreddit` (old.reddit) is the live source. Kept in case Reddit
-// re-enables it — same return type, so swapping it back in is a one-line change.
-#[allow(dead_code)]
-async fn fetch_subreddit(client: &Client, subreddit: &str) -> Result<Vec<RedditPost>, FetchError> {
+// The public .json endpoint 403s, but the same listings are served from
+// oauth.reddit.com to registered apps. The app-only (client credentials) grant
+// needs no user account; tokens last a day, so one per scrape cycle is plenty.
+
+#[derive(Deserialize)]
+struct TokenResponse {
+    access_token: String,
+}
+
+async fn fetch_oauth_token(client: &Client, id: &str, secret: &str) -> Result<String, FetchError> {
+    let token: TokenResponse = client
+        .post(config::REDDIT_OAUTH_TOKEN_URL)
+        .basic_auth(id, Some(secret))
+        .header(USER_AGENT, config::REDDIT_OAUTH_USER_AGENT)
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body("grant_type=client_credentials")
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+    Ok(token.access_token)
+}
+
+async fn fetch_oauth(
+    client: &Client,
+    token: &str,
+    subreddit: &str,
+    sort: &str,
+    limit: usize,
+) -> Result<Vec<RedditPost>, FetchError> {
+    let (path, window) = listing(sort);
     let url = format!(
-        // Public .json endpoint avoids OAuth complexity. +5 margin for stickied/deleted posts filtered below.
-        "https://www.reddit.com/r/{}/hot.json?limit={}&raw_json=1",
+        // +5 margin for stickied/deleted posts filtered below.
+        "{}/r/{}/{}?limit={}&raw_json=1&t={}",
+        config::REDDIT_OAUTH_API_BASE,
         subreddit,
-        config::REDDIT_POSTS_PER_SUB + 5
+        path,
+        limit + 5,
+        window.unwrap_or_default()
     );
 
+    // Reddit's API rules require a descriptive User-Agent instead of a browser one.
     let body: serde_json::Value = client
         .get(&url)
+        .bearer_auth(token)
+        .header(USER_AGENT, config::REDDIT_OAUTH_USER_AGENT)
         .send()
         .await?
         .error_for_status()?
         .json()
         .await?;
 
+    Ok(parse_api_listing(&body, limit))
+}
+
+fn parse_api_listing(body: &serde_json::Value, limit: usize) -> Vec<RedditPost> {
     let empty = vec![];
     let children = body["data"]["children"].as_array().unwrap_or(&empty);
 
-    let posts: Vec<_> = children
+    children
         .iter()
-        .filter_map(|c| serde_json::from_value::<RedditPost>(c["data"].clone()).ok())
+        .filter_map(|c| serde_json::from_value::<ApiPost>(c["data"].clone()).ok())
         .filter(|p| !p.stickied && p.author != "[deleted]")
-        .take(config::REDDIT_POSTS_PER_SUB)
-        .map(RedditPost::finalize)
-        .collect();
+        .take(limit)
+        .map(ApiPost::into_post)
+        .collect()
+}
 
+// ===== Source 3: RSS =====
+//
+// The `.rss` listing is an Atom feed served from www.reddit.com and is blocked
+// far less often than HTML. It carries no score or comment counts, so RSS-served
+// posts sort to the bottom of the merged "all" view.
+
+async fn fetch_rss(
+    client: &Client,
+    subreddit: &str,
+    sort: &str,
+    limit: usize,
+) -> Result<Vec<RedditPost>, FetchError> {
+    let (path, window) = listing(sort);
+    let url = format!(
+        "https://www.reddit.com/r/{}/{}/.rss?limit={}&t={}",
+        subreddit,
+        path,
+        limit,
+        window.unwrap_or_default()
+    );
+    let body = client
+        .get(&url)
+        .send()
+        .await?
+        .error_for_status()?
+        .text()
+        .await?;
+
+    let subreddit = subreddit.to_string();
+    // spawn_blocking: XML parsing is CPU-bound.
+    let posts = tokio::task::spawn_blocking(move || parse_rss(&body, &subreddit, limit)).await??;
+    Ok(posts)
+}
+
+fn parse_rss(
+    xml: &str,
+    subreddit: &str,
+    limit: usize,
+) -> Result<Vec<RedditPost>, roxmltree::Error> {
+    let posts = feeds::parse_feed(xml, subreddit)?
+        .into_iter()
+        .map(|item| {
+            // Entry links are absolute comment-page URLs; `finalize` re-adds the host.
+            let permalink = item
+                .link
+                .strip_prefix("https://www.reddit.com")
+                .unwrap_or(&item.link)
+                .to_string();
+            // Multireddit feeds mix subreddits; the permalink says which one.
+            let subreddit = permalink.split('/').nth(2).unwrap_or(subreddit).to_string();
+            RedditPost {
+                title: item.title,
+                url: String::new(),
+                permalink,
+                domain: format!("self.{subreddit}"),
+                subreddit,
+                score: 0,
+                author: item
+                    .author
+                    .map(|a| a.trim_start_matches("/u/").to_string())
+                    .unwrap_or_default(),
+                created_at: item.published as f64,
+                comment_count: 0,
+                // The outbound link is buried in the entry's HTML; point at the thread.
+                is_self: true,
+                source: RedditSource::Rss,
+            }
+            .finalize()
+        })
+        .take(limit)
+        .collect();
     Ok(posts)
 }
 
@@ -265,7 +646,7 @@ mod tests {
                 )
                 .await;
             }
-            results.push(scrape_subreddit(&client, sub).await);
+            results.push(scrape_subreddit(&client, sub, "hot", config::REDDIT_POSTS_PER_SUB).await);
         }
 
         let failures: Vec<_> = config::REDDIT_SUBREDDITS
@@ -290,7 +671,8 @@ mod tests {
         let client = test_client();
         let feed = fetch_reddit_feed(&client).await;
 
-        let all = feed.get("all").expect("missing 'all' key");
+        let key = ("all".to_string(), "hot".to_string());
+        let all = feed.get(&key).expect("missing ('all', 'hot') key");
         assert!(!all.is_empty(), "all feed returned no posts");
         assert!(all.len() <= config::REDDIT_ALL_VIEW_LIMIT);
         for post in all {
@@ -317,4 +699,131 @@ mod tests {
             "no post has comments — data-comments-count not parsed"
         );
     }
+
+    #[test]
+    fn listing_url_applies_sort_and_window() {
+        assert!(
+            listing_url("rust", "hot", 30).starts_with("https://old.reddit.com/r/rust/hot/?limit=")
+        );
+        let top = listing_url("NixOS", "top-week", 30);
+        assert!(top.contains("/r/NixOS/top/?limit=") && top.ends_with("&t=week"));
+        assert_eq!(sorts_for("NixOS"), &["hot", "top-week"]);
+        assert_eq!(sorts_for("golang"), config::REDDIT_DEFAULT_SORTS);
+    }
+
+    #[test]
+    fn rss_entries_normalize_through_finalize() {
+        let xml = r#"<feed xmlns="http://www.w3.org/2005/Atom">
+              <entry>
+                <author><name>/u/ferris</name></author>
+                <id>t3_abc123</id>
+                <link href="https://www.reddit.com/r/rust/comments/abc123/hello/"/>
+                <updated>2026-10-18T08:00:00+00:00</updated>
+                <title>Hello from RSS</title>
+              </entry>
+            </feed>"#;
+        let posts = parse_rss(xml, "Rust", 30).unwrap();
+        let post = &posts[0];
+        assert_eq!(post.author, "ferris");
+        assert_eq!(post.subreddit, "rust");
+        assert_eq!(
+            post.permalink,
+            "https://www.reddit.com/r/rust/comments/abc123/hello/"
+        );
+        assert_eq!(post.url, post.permalink);
+        assert_eq!(post.source, RedditSource::Rss);
+
+        // Entries from a multireddit feed are attributed by permalink.
+        let multi = parse_rss(&xml.replace("/r/rust/", "/r/Zig/"), "rust+zig", 30).unwrap();
+        assert_eq!(multi[0].subreddit, "zig");
+        let split = split_by_subreddit(multi);
+        assert_eq!(split["zig"].len(), 1);
+    }
+
+    #[test]
+    fn api_listing_maps_to_post_fields() {
+        let body = serde_json::json!({ "data": { "children": [
+            { "data": {
+                "title": "Announcement", "permalink": "/r/rust/comments/1/", "subreddit": "rust",
+                "score": 5, "author": "mods", "created_utc": 1792314000.0, "num_comments": 2,
+                "stickied": true
+            } },
+            { "data": {
+                "title": "Rust 1.95", "url": "https://blog.rust-lang.org/", "permalink": "/r/rust/comments/2/",
+                "subreddit": "Rust", "score": 900, "author": "ferris", "created_utc": 1792314000.0,
+                "num_comments": 120, "domain": "blog.rust-lang.org", "is_self": false
+            } }
+        ] } });
+        let posts = parse_api_listing(&body, 30);
+        assert_eq!(posts.len(), 1, "stickied posts are dropped");
+        let post = &posts[0];
+        assert_eq!(post.created_at, 1792314000.0);
+        assert_eq!(post.comment_count, 120);
+        assert_eq!(post.subreddit, "rust");
+        assert_eq!(post.permalink, "https://www.reddit.com/r/rust/comments/2/");
+
+        // The snapshot uses our field names, not the API's.
+        let json = serde_json::to_value(post).unwrap();
+        assert_eq!(json["comment_count"], 120);
+        assert!(json.get("num_comments").is_none());
+    }
+
+    fn post(subreddit: &str, n: usize) -> RedditPost {
+        RedditPost {
+            title: format!("{subreddit} post {n}"),
+            url: String::new(),
+            permalink: format!("/r/{subreddit}/comments/{n}/"),
+            subreddit: subreddit.to_string(),
+            score: 0,
+            author: String::new(),
+            created_at: 0.0,
+            comment_count: 0,
+            domain: String::new(),
+            is_self: true,
+            source: RedditSource::OldReddit,
+        }
+        .finalize()
+    }
+
+    /// Runs `fetch_batch` against canned listings: the combined one holds
+    /// `counts` posts per subreddit, a dedicated one a full page. Returns the
+    /// per-subreddit lengths and the requests made.
+    async fn run_batch(counts: &[(&str, usize)]) -> (Vec<usize>, Vec<String>) {
+        let batch: Vec<&str> = counts.iter().map(|&(sub, _)| sub).collect();
+        let requests = std::cell::RefCell::new(Vec::new());
+        let fetch = |subreddits: String, limit: usize| {
+            let posts: Vec<RedditPost> = match counts.iter().find(|&&(sub, _)| sub == subreddits) {
+                Some(&(sub, _)) => (0..limit).map(|n| post(sub, n)).collect(),
+                None => counts
+                    .iter()
+                    .flat_map(|&(sub, n)| (0..n).map(move |n| post(sub, n)))
+                    .collect(),
+            };
+            requests.borrow_mut().push(subreddits);
+            async move { posts }
+        };
+        let split = fetch_batch(&batch, "hot", &mut true, fetch).await;
+        let lengths = split.iter().map(|(_, posts)| posts.len()).collect();
+        (lengths, requests.into_inner())
+    }
+
+    #[tokio::test]
+    async fn full_skewed_batch_refetches_only_starved_subreddits() {
+        // A full listing: r/rust crowds out r/zig, r/golang keeps enough to show.
+        let (lengths, requests) = run_batch(&[("rust", 70), ("golang", 20), ("zig", 5)]).await;
+        assert_eq!(
+            lengths,
+            [
+                config::REDDIT_POSTS_PER_SUB,
+                20,
+                config::REDDIT_POSTS_PER_SUB
+            ]
+        );
+        assert_eq!(requests, ["rust+golang+zig", "zig"]);
+
+        // Not full: the window simply has few posts, so nothing is refetched.
+        let (lengths, requests) = run_batch(&[("zig", 10), ("NixOS", 8), ("bun", 4)]).await;
+        assert_eq!(lengths, [10, 8, 4]);
+        assert_eq!(requests, ["zig+NixOS+bun"]);
+    }
 }
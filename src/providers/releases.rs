@@ -0,0 +1,314 @@
+use std::cmp::Reverse;
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use reqwest::header::ACCEPT;
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+
+use super::{FetchError, feeds, github_pace};
+use crate::client::Client;
+use crate::config;
+
+/// Key: "owner/repo" or "all" for the merged view.
+pub type GhReleases = HashMap<String, Vec<GhRelease>>;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GhRelease {
+    /// "owner/repo" from `GITHUB_RELEASE_REPOS`.
+    pub repo: String,
+    pub tag: String,
+    /// Release title; GitHub falls back to the tag when none was given.
+    pub name: String,
+    pub url: String,
+    pub prerelease: bool,
+    pub published: DateTime<Utc>,
+    /// Breaking bump over the repo's previous release (see `breaking_key`).
+    pub major_bump: bool,
+}
+
+#[derive(Deserialize)]
+struct ApiRelease {
+    tag_name: String,
+    #[serde(default)]
+    name: Option<String>,
+    html_url: String,
+    #[serde(default)]
+    prerelease: bool,
+    #[serde(default)]
+    draft: bool,
+    #[serde(default)]
+    published_at: Option<DateTime<Utc>>,
+}
+
+/// With `GITHUB_TOKEN` set, releases come from the REST API (exact prerelease
+/// flag, 5000 req/h). Without it, the unauthenticated REST limit (60 req/h) is
+/// too low for a long watchlist, so the per-repo `releases.atom` feed is used
+/// and prereleases are inferred from the tag's semver suffix.
+pub async fn fetch_releases(client: &Client) -> GhReleases {
+    let token = config::github_token();
+    info!(
+        repos = config::GITHUB_RELEASE_REPOS.len(),
+        source = if token.is_some() { "api" } else { "atom" },
+        "github releases: fetching"
+    );
+
+    let mut feed = GhReleases::with_capacity(config::GITHUB_RELEASE_REPOS.len() + 1);
+    for &repo in config::GITHUB_RELEASE_REPOS {
+        github_pace().await;
+        let result = match token.as_deref() {
+            Some(token) => fetch_api(client, repo, token).await,
+            None => fetch_atom(client, repo).await,
+        };
+        match result {
+            Ok(mut releases) => {
+                mark_major_bumps(&mut releases);
+                releases.truncate(config::GITHUB_RELEASES_PER_REPO);
+                feed.insert(repo.to_string(), releases);
+            }
+            Err(e) => warn!(repo, error = %e, "failed to fetch github releases"),
+        }
+    }
+
+    let mut all: Vec<GhRelease> = feed.values().flatten().cloned().collect();
+    all.sort_by_key(|r| Reverse(r.published));
+    all.truncate(config::GITHUB_RELEASES_ALL_VIEW_LIMIT);
+    feed.insert(config::FILTER_ALL.to_string(), all);
+    feed
+}
+
+async fn fetch_api(client: &Client, repo: &str, token: &str) -> Result<Vec<GhRelease>, FetchError> {
+    // One extra release so the oldest shown one can still be compared.
+    let url = format!(
+        "{}/repos/{repo}/releases?per_page={}",
+        config::GITHUB_API_BASE,
+        config::GITHUB_RELEASES_PER_REPO + 1
+    );
+    let releases: Vec<ApiRelease> = client
+        .get(&url)
+        .header(ACCEPT, "application/vnd.github+json")
+        .bearer_auth(token)
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+
+    Ok(releases
+        .into_iter()
+        .filter(|r| !r.draft)
+        .map(|r| GhRelease {
+            repo: repo.to_string(),
+            name: r
+                .name
+                .filter(|n| !n.trim().is_empty())
+                .unwrap_or_else(|| r.tag_name.clone()),
+            tag: r.tag_name,
+            url: r.html_url,
+            prerelease: r.prerelease,
+            published: r.published_at.unwrap_or_default(),
+            major_bump: false,
+        })
+        .collect())
+}
+
+async fn fetch_atom(client: &Client, repo: &str) -> Result<Vec<GhRelease>, FetchError> {
+    let url = format!("https://github.com/{repo}/releases.atom");
+    let body = client
+        .get(&url)
+        .send()
+        .await?
+        .error_for_status()?
+        .text()
+        .await?;
+
+    let repo_owned = repo.to_string();
+    let releases = tokio::task::spawn_blocking(move || parse_atom(&body, &repo_owned)).await??;
+    Ok(releases)
+}
+
+/// Entries link to `/{repo}/releases/tag/{tag}`; the title is the release name.
+fn parse_atom(xml: &str, repo: &str) -> Result<Vec<GhRelease>, roxmltree::Error> {
+    Ok(feeds::parse_feed(xml, repo)?
+        .into_iter()
+        .filter_map(|item| {
+            let tag = item.link.rsplit_once("/releases/tag/")?.1.to_string();
+            Some(GhRelease {
+                repo: repo.to_string(),
+                prerelease: parse_version(&tag).is_some_and(|v| v.pre),
+                name: if item.title.is_empty() {
+                    tag.clone()
+                } else {
+                    item.title
+                },
+                tag,
+                url: item.link,
+                published: DateTime::from_timestamp(item.published as i64, 0).unwrap_or_default(),
+                major_bump: false,
+            })
+        })
+        .collect())
+}
+
+struct Version<'a> {
+    /// Everything before the first digit. Repos that release several crates
+    /// tag each with its own (`tokio-`, `tokio-util-`).
+    prefix: &'a str,
+    major: u64,
+    minor: u64,
+    pre: bool,
+}
+
+/// Lenient semver: splits off any prefix before the first digit (`v1.2.3`,
+/// `release-1.2`, `nvim-0.10.0`) and treats missing components as 0. Date tags
+/// (`2026-10-13`, as rust-analyzer uses) are not versions and give `None`.
+fn parse_version(tag: &str) -> Option<Version<'_>> {
+    let start = tag.find(|c: char| c.is_ascii_digit())?;
+    let rest = &tag[start..];
+    if is_date(rest) {
+        return None;
+    }
+    let core = rest.split(['-', '+']).next().unwrap_or(rest);
+    // `+build` metadata is not a prerelease; `-rc.1`, `-beta` etc. are.
+    let pre = rest[core.len()..].starts_with('-');
+    let mut parts = core.split('.').map(|p| p.parse::<u64>().ok());
+    let major = parts.next()??;
+    let minor = parts.next().flatten().unwrap_or(0);
+    Some(Version {
+        prefix: &tag[..start],
+        major,
+        minor,
+        pre,
+    })
+}
+
+/// Starts with `YYYY-MM-DD`.
+fn is_date(s: &str) -> bool {
+    let b = s.as_bytes();
+    b.len() >= 10
+        && b[4] == b'-'
+        && b[7] == b'-'
+        && [0..4, 5..7, 8..10]
+            .into_iter()
+            .all(|r| b[r].iter().all(u8::is_ascii_digit))
+}
+
+/// Cargo's compatibility rule: for 0.x the minor version is the breaking one.
+fn breaking_key(v: &Version) -> (u64, u64) {
+    if v.major == 0 {
+        (0, v.minor)
+    } else {
+        (v.major, 0)
+    }
+}
+
+/// Releases are newest first; each is compared with the next older one that
+/// has the same tag prefix, so one crate's release isn't measured against another's.
+fn mark_major_bumps(releases: &mut [GhRelease]) {
+    if !config::GITHUB_RELEASES_HIGHLIGHT_MAJOR {
+        return;
+    }
+    let versions: Vec<Option<Version>> = releases.iter().map(|r| parse_version(&r.tag)).collect();
+    let bumps: Vec<bool> = versions
+        .iter()
+        .enumerate()
+        .map(|(i, new)| {
+            let Some(new) = new else { return false };
+            versions[i + 1..]
+                .iter()
+                .flatten()
+                .find(|old| old.prefix == new.prefix)
+                .is_some_and(|old| breaking_key(new) > breaking_key(old))
+        })
+        .collect();
+    for (release, bump) in releases.iter_mut().zip(bumps) {
+        release.major_bump = bump;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn atom_releases_parse_and_flag_major_bumps() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+            <feed xmlns="http://www.w3.org/2005/Atom">
+              <title>Release notes from claws</title>
+              <entry>
+                <id>tag:github.com,2008:Repository/1/v2.0.0-rc.1</id>
+                <updated>2026-10-18T09:00:00Z</updated>
+                <link rel="alternate" type="text/html" href="https://github.com/ferris/claws/releases/tag/v2.0.0-rc.1"/>
+                <title>Claws 2.0 RC 1</title>
+              </entry>
+              <entry>
+                <id>tag:github.com,2008:Repository/1/v1.4.2</id>
+                <updated>2026-09-01T09:00:00Z</updated>
+                <link rel="alternate" type="text/html" href="https://github.com/ferris/claws/releases/tag/v1.4.2"/>
+                <title>v1.4.2</title>
+              </entry>
+              <entry>
+                <id>tag:github.com,2008:Repository/1/v1.4.1</id>
+                <updated>2026-08-01T09:00:00Z</updated>
+                <link rel="alternate" type="text/html" href="https://github.com/ferris/claws/releases/tag/v1.4.1"/>
+                <title>v1.4.1</title>
+              </entry>
+            </feed>"#;
+        let mut releases = parse_atom(xml, "ferris/claws").unwrap();
+        mark_major_bumps(&mut releases);
+
+        assert_eq!(releases.len(), 3);
+        assert_eq!(releases[0].tag, "v2.0.0-rc.1");
+        assert_eq!(releases[0].name, "Claws 2.0 RC 1");
+        assert!(releases[0].prerelease);
+        assert!(releases[0].major_bump);
+        assert!(!releases[1].prerelease);
+        assert!(!releases[1].major_bump);
+
+        // 0.x: a minor bump is breaking.
+        assert!(
+            breaking_key(&parse_version("nvim-0.11.0").unwrap())
+                > breaking_key(&parse_version("v0.10.4").unwrap())
+        );
+
+        // Date tags: neither prereleases nor a new major each January.
+        let mut dated = parse_atom(
+            &feed(&["2027-01-05", "2026-12-29"]),
+            "rust-lang/rust-analyzer",
+        )
+        .unwrap();
+        mark_major_bumps(&mut dated);
+        assert!(dated.iter().all(|r| !r.prerelease && !r.major_bump));
+
+        // One repo, several crates: each is compared with its own previous release.
+        let mut crates = parse_atom(
+            &feed(&[
+                "tokio-util-0.8.0",
+                "tokio-1.40.0",
+                "tokio-util-0.7.12",
+                "tokio-macros-2.4.0",
+                "tokio-1.39.3",
+                "tokio-macros-2.3.0",
+            ]),
+            "tokio-rs/tokio",
+        )
+        .unwrap();
+        mark_major_bumps(&mut crates);
+        let bumps: Vec<bool> = crates.iter().map(|r| r.major_bump).collect();
+        assert_eq!(bumps, [true, false, false, false, false, false]);
+    }
+
+    fn feed(tags: &[&str]) -> String {
+        let entries: String = tags
+            .iter()
+            .map(|tag| {
+                format!(
+                    r#"<entry><id>{tag}</id><updated>2026-10-18T09:00:00Z</updated>
+                    <link rel="alternate" href="https://github.com/o/r/releases/tag/{tag}"/>
+                    <title>{tag}</title></entry>"#
+                )
+            })
+            .collect();
+        format!(r#"<feed xmlns="http://www.w3.org/2005/Atom">{entries}</feed>"#)
+    }
+}
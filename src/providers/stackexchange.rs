@@ -0,0 +1,208 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use scraper::Html;
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+use url::Url;
+
+use super::{FetchError, jittered_delay};
+use crate::client::Client;
+use crate::config;
+
+/// Key: (site label or "all", sort).
+pub type StackExchangeFeed = HashMap<(String, String), Vec<StackQuestion>>;
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct StackQuestion {
+    pub title: String,
+    pub link: String,
+    pub score: i64,
+    pub answer_count: u32,
+    #[serde(default)]
+    pub accepted_answer_id: Option<u64>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub creation_date: DateTime<Utc>,
+    /// Site label from `STACKEXCHANGE_SITES`, filled in after parsing.
+    #[serde(default)]
+    pub site: String,
+}
+
+impl StackQuestion {
+    pub fn is_accepted(&self) -> bool {
+        self.accepted_answer_id.is_some()
+    }
+}
+
+/// Common wrapper around every API response. `backoff` (seconds) is sent when
+/// the caller must pause before hitting the same method again; ignoring it gets
+/// the IP throttled for the rest of the day.
+#[derive(Deserialize)]
+struct Wrapper {
+    #[serde(default)]
+    items: Vec<StackQuestion>,
+    #[serde(default)]
+    backoff: Option<u64>,
+    #[serde(default)]
+    quota_remaining: Option<u32>,
+    #[serde(default)]
+    quota_max: Option<u32>,
+}
+
+/// Fetch every (site, sort) pair sequentially, waiting out any `backoff` the
+/// API asks for and stopping early when the daily quota runs out.
+pub async fn fetch_stackexchange_feed(client: &Client) -> StackExchangeFeed {
+    tracing::info!(
+        sites = config::STACKEXCHANGE_SITES.len(),
+        "stackexchange: fetching"
+    );
+
+    let key = config::stackexchange_key();
+    let mut feed = StackExchangeFeed::new();
+    let mut backoff = None;
+    let mut first = true;
+
+    'sites: for &(label, site, tag) in config::STACKEXCHANGE_SITES {
+        for &sort in config::STACKEXCHANGE_SORTS {
+            if let Some(secs) = backoff.take() {
+                warn!(secs, "stackexchange: API asked to back off");
+                tokio::time::sleep(Duration::from_secs(secs)).await;
+            } else if !first {
+                jittered_delay(
+                    config::STACKEXCHANGE_REQUEST_INTERVAL_MS,
+                    config::STACKEXCHANGE_REQUEST_JITTER_MS,
+                )
+                .await;
+            }
+            first = false;
+
+            let wrapper = match fetch_questions(client, site, tag, sort, key.as_deref()).await {
+                Ok(w) => w,
+                Err(e) => {
+                    warn!(site = label, sort, error = %e, "failed to fetch stack exchange questions");
+                    continue;
+                }
+            };
+            backoff = wrapper.backoff;
+
+            let questions = wrapper
+                .items
+                .into_iter()
+                .map(|q| StackQuestion {
+                    title: decode_entities(&q.title),
+                    site: label.to_string(),
+                    ..q
+                })
+                .collect();
+            feed.insert((label.to_string(), sort.to_string()), questions);
+
+            if let Some(remaining) = wrapper.quota_remaining {
+                tracing::debug!(remaining, max = wrapper.quota_max, "stackexchange: quota");
+                if remaining == 0 {
+                    warn!("stackexchange: daily quota exhausted, skipping remaining sites");
+                    break 'sites;
+                }
+                if remaining < config::STACKEXCHANGE_QUOTA_WARN {
+                    warn!(remaining, "stackexchange: daily quota running low");
+                }
+            }
+        }
+    }
+
+    // Merged view per sort: every site's questions ranked by score.
+    for &sort in config::STACKEXCHANGE_SORTS {
+        let mut all: Vec<StackQuestion> = feed
+            .iter()
+            .filter(|((_, s), _)| s == sort)
+            .flat_map(|(_, questions)| questions.iter().cloned())
+            .collect();
+        all.sort_by_key(|q| std::cmp::Reverse(q.score));
+        all.truncate(config::STACKEXCHANGE_ALL_VIEW_LIMIT);
+        feed.insert((config::FILTER_ALL.to_string(), sort.to_string()), all);
+    }
+
+    feed
+}
+
+async fn fetch_questions(
+    client: &Client,
+    site: &str,
+    tag: Option<&str>,
+    sort: &str,
+    key: Option<&str>,
+) -> Result<Wrapper, FetchError> {
+    let pagesize = config::STACKEXCHANGE_QUESTIONS_PER_SITE.to_string();
+    let mut params = vec![
+        ("site", site),
+        ("sort", sort),
+        ("order", "desc"),
+        ("pagesize", &pagesize),
+    ];
+    if let Some(tag) = tag {
+        params.push(("tagged", tag));
+    }
+    if let Some(key) = key {
+        params.push(("key", key));
+    }
+    let url = Url::parse_with_params(
+        &format!("{}/questions", config::STACKEXCHANGE_API_BASE),
+        &params,
+    )?;
+
+    // The API always gzips; reqwest's `gzip` feature decodes transparently.
+    Ok(client
+        .get(url)
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?)
+}
+
+/// Titles arrive HTML-escaped (`&quot;`, `&#39;`); maud would escape them again.
+fn decode_entities(s: &str) -> String {
+    Html::parse_fragment(s).root_element().text().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wrapper_parses_items_backoff_and_quota() {
+        let body = r#"{
+            "items": [{
+                "tags": ["rust", "borrow-checker"],
+                "owner": { "display_name": "ferris" },
+                "is_answered": true,
+                "view_count": 120,
+                "accepted_answer_id": 7,
+                "answer_count": 2,
+                "score": 14,
+                "last_activity_date": 1792300000,
+                "creation_date": 1792200000,
+                "question_id": 42,
+                "link": "https://stackoverflow.com/questions/42/why",
+                "title": "Why does &quot;cannot borrow&quot; happen here?"
+            }],
+            "has_more": true,
+            "backoff": 10,
+            "quota_max": 300,
+            "quota_remaining": 287
+        }"#;
+        let wrapper: Wrapper = serde_json::from_str(body).unwrap();
+        assert_eq!(wrapper.backoff, Some(10));
+        assert_eq!(wrapper.quota_remaining, Some(287));
+
+        let q = &wrapper.items[0];
+        assert!(q.is_accepted());
+        assert_eq!(q.answer_count, 2);
+        assert_eq!(
+            decode_entities(&q.title),
+            r#"Why does "cannot borrow" happen here?"#
+        );
+    }
+}
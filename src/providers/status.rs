@@ -0,0 +1,272 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+
+use super::{FetchError, feeds};
+use crate::client::Client;
+use crate::config::{self, StatusSource};
+
+/// One entry per `STATUS_PAGES` service, in config order. A service that fails
+/// to fetch is kept as `Indicator::Unknown` rather than reusing last cycle's
+/// answer: a stale "operational" is worse than no answer on a status board.
+pub type StatusFeed = Vec<ServiceStatus>;
+
+/// Statuspage's page-level indicator, plus `Unknown` for fetch failures.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Indicator {
+    None,
+    Maintenance,
+    Minor,
+    Major,
+    Critical,
+    #[serde(other)]
+    Unknown,
+}
+
+impl Indicator {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::None => "operational",
+            Self::Maintenance => "maintenance",
+            Self::Minor => "minor",
+            Self::Major => "major",
+            Self::Critical => "critical",
+            Self::Unknown => "unknown",
+        }
+    }
+
+    /// Minor outages and worse; maintenance is planned and not highlighted.
+    pub fn is_degraded(self) -> bool {
+        matches!(self, Self::Minor | Self::Major | Self::Critical)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServiceStatus {
+    pub name: String,
+    /// Human-facing status page.
+    pub url: String,
+    pub indicator: Indicator,
+    /// e.g. "All Systems Operational", "Partial System Outage".
+    pub description: String,
+    pub degraded_components: Vec<String>,
+    pub incidents: Vec<StatusEvent>,
+    pub maintenances: Vec<StatusEvent>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatusEvent {
+    pub name: String,
+    pub url: String,
+    /// "investigating", "identified", "scheduled", "in_progress", ...
+    pub status: String,
+    /// Last update for incidents, start for maintenance.
+    pub at: DateTime<Utc>,
+}
+
+#[derive(Deserialize)]
+struct Summary {
+    status: PageStatus,
+    #[serde(default)]
+    components: Vec<Component>,
+    #[serde(default)]
+    incidents: Vec<Incident>,
+    #[serde(default)]
+    scheduled_maintenances: Vec<Incident>,
+}
+
+#[derive(Deserialize)]
+struct PageStatus {
+    indicator: Indicator,
+    description: String,
+}
+
+#[derive(Deserialize)]
+struct Component {
+    name: String,
+    status: String,
+    /// Group headers repeat their children's worst status.
+    #[serde(default)]
+    group: bool,
+}
+
+#[derive(Deserialize)]
+struct Incident {
+    name: String,
+    status: String,
+    #[serde(default)]
+    shortlink: Option<String>,
+    #[serde(default)]
+    updated_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    scheduled_for: Option<DateTime<Utc>>,
+}
+
+/// Status pages are on unrelated hosts, so they are fetched concurrently.
+pub async fn fetch_status(client: &Client) -> StatusFeed {
+    info!(services = config::STATUS_PAGES.len(), "status: fetching");
+
+    let futs = config::STATUS_PAGES
+        .iter()
+        .map(|(name, source)| async move {
+            let result = match *source {
+                StatusSource::Statuspage(base) => fetch_statuspage(client, base).await,
+                StatusSource::Rss { feed, page } => fetch_rss(client, feed, page).await,
+            };
+            result.unwrap_or_else(|e| {
+                warn!(service = name, error = %e, "failed to fetch status");
+                let url = match *source {
+                    StatusSource::Statuspage(base) => base,
+                    StatusSource::Rss { page, .. } => page,
+                };
+                ServiceStatus {
+                    name: String::new(),
+                    url: url.to_string(),
+                    indicator: Indicator::Unknown,
+                    description: "status unavailable".to_string(),
+                    degraded_components: Vec::new(),
+                    incidents: Vec::new(),
+                    maintenances: Vec::new(),
+                }
+            })
+        });
+
+    futures::future::join_all(futs)
+        .await
+        .into_iter()
+        .zip(config::STATUS_PAGES)
+        .map(|(status, &(name, _))| ServiceStatus {
+            name: name.to_string(),
+            ..status
+        })
+        .collect()
+}
+
+async fn fetch_statuspage(client: &Client, base: &str) -> Result<ServiceStatus, FetchError> {
+    let summary: Summary = client
+        .get(format!("{base}/api/v2/summary.json"))
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+    Ok(from_summary(summary, base))
+}
+
+fn from_summary(summary: Summary, base: &str) -> ServiceStatus {
+    let event = |i: Incident, at: Option<DateTime<Utc>>| StatusEvent {
+        url: i.shortlink.unwrap_or_else(|| base.to_string()),
+        name: i.name,
+        status: i.status,
+        at: at.unwrap_or_default(),
+    };
+    ServiceStatus {
+        name: String::new(),
+        url: base.to_string(),
+        indicator: summary.status.indicator,
+        description: summary.status.description,
+        degraded_components: summary
+            .components
+            .into_iter()
+            .filter(|c| !c.group && c.status != "operational")
+            .map(|c| c.name)
+            .collect(),
+        incidents: summary
+            .incidents
+            .into_iter()
+            .map(|i| {
+                let at = i.updated_at;
+                event(i, at)
+            })
+            .collect(),
+        maintenances: summary
+            .scheduled_maintenances
+            .into_iter()
+            .map(|i| {
+                let at = i.scheduled_for;
+                event(i, at)
+            })
+            .collect(),
+    }
+}
+
+/// Feeds without a summary API (e.g. AWS) only list events; anything posted
+/// within `STATUS_RSS_ACTIVE_HOURS` counts as an active incident.
+async fn fetch_rss(client: &Client, feed: &str, page: &str) -> Result<ServiceStatus, FetchError> {
+    let body = client
+        .get(feed)
+        .send()
+        .await?
+        .error_for_status()?
+        .text()
+        .await?;
+    let feed = feed.to_string();
+    let items = tokio::task::spawn_blocking(move || feeds::parse_feed(&body, &feed)).await??;
+
+    let cutoff = Utc::now().timestamp() as u64 - config::STATUS_RSS_ACTIVE_HOURS * 3600;
+    let incidents: Vec<StatusEvent> = items
+        .into_iter()
+        .filter(|item| item.published >= cutoff)
+        .map(|item| StatusEvent {
+            name: item.title,
+            url: item.link,
+            status: String::new(),
+            at: DateTime::from_timestamp(item.published as i64, 0).unwrap_or_default(),
+        })
+        .collect();
+
+    let (indicator, description) = if incidents.is_empty() {
+        (Indicator::None, "No recent events")
+    } else {
+        (Indicator::Minor, "Recent events reported")
+    };
+    Ok(ServiceStatus {
+        name: String::new(),
+        url: page.to_string(),
+        indicator,
+        description: description.to_string(),
+        degraded_components: Vec::new(),
+        incidents,
+        maintenances: Vec::new(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn summary_maps_indicator_components_and_events() {
+        let body = r#"{
+            "page": { "id": "x", "name": "Crabhub", "url": "https://status.crabhub.example" },
+            "status": { "indicator": "major", "description": "Partial System Outage" },
+            "components": [
+                { "id": "a", "name": "API", "status": "major_outage", "group": false },
+                { "id": "b", "name": "Git Operations", "status": "operational", "group": false },
+                { "id": "c", "name": "Everything", "status": "major_outage", "group": true }
+            ],
+            "incidents": [{
+                "name": "Elevated API errors", "status": "investigating", "impact": "major",
+                "shortlink": "https://stspg.io/abc", "updated_at": "2026-10-18T09:00:00.000Z"
+            }],
+            "scheduled_maintenances": [{
+                "name": "Database upgrade", "status": "scheduled", "impact": "maintenance",
+                "scheduled_for": "2026-10-21T02:00:00.000Z", "updated_at": "2026-10-10T00:00:00.000Z"
+            }]
+        }"#;
+        let summary: Summary = serde_json::from_str(body).unwrap();
+        let status = from_summary(summary, "https://status.crabhub.example");
+
+        assert_eq!(status.indicator, Indicator::Major);
+        assert!(status.indicator.is_degraded());
+        assert_eq!(status.degraded_components, ["API"]);
+        assert_eq!(status.incidents[0].url, "https://stspg.io/abc");
+        assert_eq!(status.maintenances[0].url, "https://status.crabhub.example");
+        assert_eq!(status.maintenances[0].at.timestamp(), 1792548000);
+
+        let odd: PageStatus =
+            serde_json::from_str(r#"{ "indicator": "catastrophic", "description": "?" }"#).unwrap();
+        assert_eq!(odd.indicator, Indicator::Unknown);
+    }
+}
@@ -0,0 +1,241 @@
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+use chrono::{DateTime, Utc};
+use scraper::{Html, Selector};
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+
+use super::{FetchError, jittered_delay, text};
+use crate::client::Client;
+use crate::config;
+
+/// Key: group from `TILDES_GROUPS` (e.g. "~comp") or "all" for the merged view.
+pub type TildesFeed = HashMap<String, Vec<TildesTopic>>;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TildesTopic {
+    pub title: String,
+    /// Linked URL, or the topic page for text topics.
+    pub url: String,
+    pub topic_url: String,
+    /// Listings of a group include its subgroups, e.g. "~comp.programming".
+    pub group: String,
+    pub tags: Vec<String>,
+    pub votes: u64,
+    pub comment_count: u64,
+    pub author: String,
+    pub posted_at: DateTime<Utc>,
+}
+
+// Tildes has no public API; group listings are server-rendered with one
+// `article.topic` per topic, and anonymous visitors still see vote counts.
+
+struct TildesSelectors {
+    topic: Selector,
+    title: Selector,
+    group: Selector,
+    tag: Selector,
+    comments: Selector,
+    votes: Selector,
+    time: Selector,
+}
+
+static SELECTORS: LazyLock<Option<TildesSelectors>> = LazyLock::new(|| {
+    Some(TildesSelectors {
+        topic: Selector::parse("article.topic").ok()?,
+        title: Selector::parse("h1.topic-title a").ok()?,
+        group: Selector::parse(".topic-group a").ok()?,
+        tag: Selector::parse(".topic-tags a").ok()?,
+        comments: Selector::parse(".topic-info-comments a").ok()?,
+        votes: Selector::parse(".topic-voting-votes").ok()?,
+        time: Selector::parse("time[datetime]").ok()?,
+    })
+});
+
+/// Returns a feed keyed by group + an "all" entry with the merged top-N of
+/// every group, sorted by votes.
+pub async fn fetch_tildes_feed(client: &Client) -> TildesFeed {
+    info!(groups = config::TILDES_GROUPS.len(), "tildes: fetching");
+
+    let mut feed = TildesFeed::with_capacity(config::TILDES_GROUPS.len() + 1);
+    for (i, &group) in config::TILDES_GROUPS.iter().enumerate() {
+        if i > 0 {
+            jittered_delay(
+                config::TILDES_REQUEST_INTERVAL_MS,
+                config::TILDES_REQUEST_JITTER_MS,
+            )
+            .await;
+        }
+        match scrape_group(client, group).await {
+            Ok(topics) => {
+                feed.insert(group.to_string(), topics);
+            }
+            Err(e) => warn!(group, error = %e, "failed to fetch tildes group"),
+        }
+    }
+
+    let mut all: Vec<TildesTopic> = feed.values().flatten().cloned().collect();
+    all.sort_by_key(|t| std::cmp::Reverse(t.votes));
+    all.truncate(config::TILDES_ALL_VIEW_LIMIT);
+    feed.insert(config::FILTER_ALL.to_string(), all);
+    feed
+}
+
+async fn scrape_group(client: &Client, group: &str) -> Result<Vec<TildesTopic>, FetchError> {
+    let url = format!(
+        "{}/{group}?order={}&per_page={}",
+        config::TILDES_BASE,
+        config::TILDES_ORDER,
+        config::TILDES_TOPICS_PER_GROUP
+    );
+    let html = client
+        .get(&url)
+        .send()
+        .await?
+        .error_for_status()?
+        .text()
+        .await?;
+
+    // spawn_blocking: scraper HTML parsing is CPU-bound.
+    let group = group.to_string();
+    let topics = tokio::task::spawn_blocking(move || parse_listing_html(&html, &group)).await?;
+    Ok(topics)
+}
+
+fn parse_listing_html(html: &str, group: &str) -> Vec<TildesTopic> {
+    let Some(sel) = SELECTORS.as_ref() else {
+        tracing::error!("tildes: CSS selectors failed to parse");
+        return Vec::new();
+    };
+
+    let document = Html::parse_document(html);
+
+    // Detect stale markup / error pages: a valid listing always has topics.
+    if document.select(&sel.topic).next().is_none() && html.len() > 1000 {
+        tracing::error!(
+            html_len = html.len(),
+            "tildes: 0 topics matched on non-empty page — markup changed or blocked"
+        );
+        return Vec::new();
+    }
+
+    let absolute = |href: &str| {
+        if href.starts_with('/') {
+            format!("{}{href}", config::TILDES_BASE)
+        } else {
+            href.to_string()
+        }
+    };
+
+    let mut topics = Vec::with_capacity(config::TILDES_TOPICS_PER_GROUP);
+    for topic in document.select(&sel.topic) {
+        let Some(title_link) = topic.select(&sel.title).next() else {
+            continue;
+        };
+        let title = text(title_link);
+        if title.is_empty() {
+            continue;
+        }
+        let url = absolute(title_link.value().attr("href").unwrap_or_default());
+
+        // "12 comments" or "No comments yet"; the link is the topic page either way.
+        let comments = topic.select(&sel.comments).next();
+        let topic_url = comments
+            .and_then(|a| a.value().attr("href"))
+            .map_or_else(|| url.clone(), absolute);
+        let comment_count = comments
+            .map(text)
+            .and_then(|t| t.split_whitespace().next()?.parse().ok())
+            .unwrap_or(0);
+
+        topics.push(TildesTopic {
+            title,
+            url,
+            topic_url,
+            // Only topics from subgroups name their group in a group listing.
+            group: topic
+                .select(&sel.group)
+                .next()
+                .map(text)
+                .unwrap_or_else(|| group.to_string()),
+            tags: topic.select(&sel.tag).map(text).collect(),
+            votes: topic
+                .select(&sel.votes)
+                .next()
+                .and_then(|v| text(v).parse().ok())
+                .unwrap_or(0),
+            comment_count,
+            author: topic
+                .value()
+                .attr("data-topic-posted-by")
+                .unwrap_or_default()
+                .to_string(),
+            posted_at: topic
+                .select(&sel.time)
+                .next()
+                .and_then(|t| t.value().attr("datetime"))
+                .and_then(|d| d.parse().ok())
+                .unwrap_or_default(),
+        });
+        if topics.len() >= config::TILDES_TOPICS_PER_GROUP {
+            break;
+        }
+    }
+
+    topics
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn listing_parses_topics_tags_and_counts() {
+        let html = r#"<html><body><ol class="topic-listing">
+            <li><article id="topic-1a2b" class="topic topic-with-excerpt" data-topic-posted-by="ferris">
+              <header><h1 class="topic-title"><a href="https://blog.example/borrowck">Borrow checker internals</a></h1></header>
+              <div class="topic-metadata">
+                <span class="topic-group"><a href="/~comp.programming" class="link-group">~comp.programming</a></span>
+                <ul class="topic-tags">
+                  <li class="label label-topic-tag"><a href="/~comp?tag=rust">rust</a></li>
+                  <li class="label label-topic-tag"><a href="/~comp?tag=compilers">compilers</a></li>
+                </ul>
+              </div>
+              <footer class="topic-info">
+                <div class="topic-info-comments"><a href="/~comp.programming/1a2b/borrow_checker_internals"><span>14 comments</span></a></div>
+                <time class="time-responsive" datetime="2026-10-18T09:00:00Z">2 hours ago</time>
+              </footer>
+              <div class="topic-voting"><span class="topic-voting-votes">37</span><span class="topic-voting-label">votes</span></div>
+            </article></li>
+            <li><article id="topic-1a2c" class="topic" data-topic-posted-by="crab">
+              <header><h1 class="topic-title"><a href="/~comp/1a2c/what_editor">What editor do you use?</a></h1></header>
+              <footer class="topic-info">
+                <div class="topic-info-comments"><a href="/~comp/1a2c/what_editor"><span>No comments yet</span></a></div>
+              </footer>
+              <div class="topic-voting"><span class="topic-voting-votes">3</span></div>
+            </article></li>
+        </ol></body></html>"#;
+        let topics = parse_listing_html(html, "~comp");
+
+        assert_eq!(topics.len(), 2);
+        assert_eq!(topics[0].url, "https://blog.example/borrowck");
+        assert_eq!(
+            topics[0].topic_url,
+            "https://tildes.net/~comp.programming/1a2b/borrow_checker_internals"
+        );
+        assert_eq!(topics[0].group, "~comp.programming");
+        assert_eq!(topics[0].tags, ["rust", "compilers"]);
+        assert_eq!(topics[0].votes, 37);
+        assert_eq!(topics[0].comment_count, 14);
+        assert_eq!(topics[0].author, "ferris");
+        assert_eq!(topics[0].posted_at.timestamp(), 1792314000);
+
+        assert_eq!(topics[1].url, "https://tildes.net/~comp/1a2c/what_editor");
+        assert_eq!(topics[1].group, "~comp");
+        assert_eq!(topics[1].comment_count, 0);
+
+        let blocked = format!("<html><body>{}</body></html>", "x".repeat(2000));
+        assert!(parse_listing_html(&blocked, "~comp").is_empty());
+    }
+}
@@ -1,48 +1,93 @@
-use chrono::{DateTime, Utc};
+use chrono::Utc;
 use maud::{Markup, PreEscaped, html};
 
 use super::shell::page_shell;
 use super::utils::{SEP, fmt_num, format_time_ago};
-use crate::config;
-use crate::providers::github::{GhTrending, TrendingRepo};
+use crate::cache::DataSnapshot;
+use crate::config::{self, HnSource};
+use crate::providers::advisories::{Advisory, AdvisoryFeed};
+use crate::providers::arxiv::{ArxivFeed, ArxivPaper};
+use crate::providers::bluesky::{BlueskyFeed, BlueskyPost};
+use crate::providers::command::{CommandPanel, ExternalItem};
+use crate::providers::crates::{self, CrateInfo, CratesFeed};
+use crate::providers::discourse::{DiscourseFeed, DiscourseTopic};
+use crate::providers::feeds::{FeedItem, Feeds};
+use crate::providers::forgejo::ForgeRepos;
+use crate::providers::github::{GhDevelopers, GhTrending, TrendingDeveloper, TrendingRepo};
 use crate::providers::hackernews::{HnPages, HnStory};
-use crate::providers::reddit::{RedditFeed, RedditPost};
-
-pub fn render_page(
-    hn_pages: &HnPages,
-    gh_trending: &GhTrending,
-    reddit_feed: &RedditFeed,
-    last_fetched: DateTime<Utc>,
-) -> String {
+use crate::providers::hiring::{HiringFeed, Job, Workplace};
+use crate::providers::huggingface::{HfRepo, HfTrending};
+use crate::providers::ingest::IngestedItem;
+use crate::providers::lemmy::{LemmyFeed, LemmyPost};
+use crate::providers::mastodon::{self, MastodonFeed, MastodonPost};
+use crate::providers::producthunt::{ProductHuntFeed, ProductLaunch};
+use crate::providers::reddit::{self, RedditFeed, RedditPost, RedditSource};
+use crate::providers::releases::{GhRelease, GhReleases};
+use crate::providers::stackexchange::{StackExchangeFeed, StackQuestion};
+use crate::providers::status::{ServiceStatus, StatusFeed};
+use crate::providers::tildes::{TildesFeed, TildesTopic};
+
+pub fn render_page(data: &DataSnapshot) -> String {
     page_shell(include_str!("../static/dashboard.css"), html! {
+        (render_status_banner(&data.status_feed))
         main #main-content .dashboard {
-            section.panel.hn-panel aria-label="Hacker News" {
+            section.panel.hn-panel data-panel="hn" aria-label="Hacker News" {
                 header.panel-header {
                     span.source-icon aria-hidden="true" { (PreEscaped(include_str!("../static/icons/hn.svg"))) }
                     a.source-name.hn-link href="https://news.ycombinator.com/" target="_blank" rel="noopener" { "Hackernews" }
                     select.hn-select aria-label="HN page" {
-                        @for (i, &(name, _)) in config::HN_PAGES.iter().enumerate() {
-                            option value=(name) selected[i == 0] { (name) }
+                        @for (i, (name, source)) in config::HN_PAGES.iter().enumerate() {
+                            option value=(name) data-href=(hn_page_url(name, source)) selected[i == 0] { (name) }
+                        }
+                    }
+                }
+                (render_hn_pages(&data.hn_pages))
+            }
+            section.panel.status-panel data-panel="status" aria-label="Service Status" {
+                header.panel-header {
+                    span.source-icon.status-icon aria-hidden="true" { (PreEscaped(include_str!("../static/icons/status.svg"))) }
+                    span.source-name { "Status" }
+                }
+                (render_status_feed(&data.status_feed))
+            }
+            @let thread = data.hiring_feed.keys().next();
+            section.panel.jobs-panel data-panel="jobs" aria-label="HN Who is hiring" {
+                header.panel-header {
+                    span.source-icon.jobs-icon aria-hidden="true" { (PreEscaped(include_str!("../static/icons/jobs.svg"))) }
+                    a.source-name.jobs-link
+                        href=(thread.map_or("https://news.ycombinator.com/submitted?id=whoishiring".to_string(), |(id, _)| format!("https://news.ycombinator.com/item?id={id}")))
+                        title=[thread.map(|(_, title)| title)]
+                        target="_blank" rel="noopener"
+                        { "HN Jobs" }
+                    select.jobs-workplace-select aria-label="Workplace" {
+                        option value=(config::FILTER_ALL) selected { (config::FILTER_ALL) }
+                        @for w in [Workplace::Remote, Workplace::Hybrid, Workplace::Onsite] {
+                            option value=(w.as_str()) { (w.as_str()) }
+                        }
+                    }
+                    select.jobs-location-select aria-label="Location" {
+                        option value=(config::FILTER_ALL) selected { (config::FILTER_ALL) }
+                        @for &(label, _) in config::HIRING_LOCATIONS {
+                            option value=(label) { (label) }
+                        }
+                    }
+                    select.jobs-tech-select aria-label="Technology" {
+                        option value=(config::FILTER_ALL) selected { (config::FILTER_ALL) }
+                        @for &(label, _) in config::HIRING_TECH {
+                            option value=(label) { (label) }
                         }
                     }
                 }
-                (render_hn_pages(hn_pages))
+                (render_jobs(&data.hiring_feed))
             }
-            section.panel.gh-panel aria-label="GitHub Trending" {
+            section.panel.gh-panel data-panel="gh" aria-label="GitHub Trending" {
                 header.panel-header {
                     span.source-icon.gh-icon aria-hidden="true" { (PreEscaped(include_str!("../static/icons/github.svg"))) }
                     a.source-name.gh-link href="https://github.com/trending" target="_blank" rel="noopener" { "GitHub Trending" }
-                    .tab-labels role="tablist" aria-label="Trending period" {
-                        @for (i, &period) in config::GITHUB_PERIODS.iter().enumerate() {
-                            input id=(format!("gh-tab-{period}"))
-                                  type="radio" name="gh-tab"
-                                  checked[i == 0];
-                            label .active[i == 0]
-                                  for=(format!("gh-tab-{period}"))
-                                  role="tab"
-                                  aria-selected=(if i == 0 { "true" } else { "false" })
-                                  aria-controls=(format!("gh-{period}"))
-                                  { (&period[..1]) }
+                    (render_tab_labels("gh", config::GITHUB_PERIODS, "Trending period"))
+                    select.gh-view-select aria-label="GitHub view" {
+                        @for &view in config::GITHUB_TRENDING_VIEWS {
+                            option value=(view) { (view) }
                         }
                     }
                     select.lang-select aria-label="Programming language" {
@@ -51,6 +96,13 @@ pub fn render_page(
                             option value=(name.to_lowercase()) { (name) }
                         }
                     }
+                    // Shown in place of the period tabs and language select in the releases view.
+                    select.releases-select aria-label="Repository" style="display: none" {
+                        option value=(config::FILTER_ALL) selected { (config::FILTER_ALL) }
+                        @for &repo in config::GITHUB_RELEASE_REPOS {
+                            option value=(repo) { (repo) }
+                        }
+                    }
                 }
                 .gh-tabs {
                     @for (i, &period) in config::GITHUB_PERIODS.iter().enumerate() {
@@ -59,31 +111,317 @@ pub fn render_page(
                             role="tabpanel"
                             aria-labelledby=(format!("gh-tab-{period}"))
                         {
-                            (render_gh_trending(gh_trending, period))
+                            (render_gh_trending(&data.gh_trending, &data.gh_developers, period))
+                        }
+                    }
+                }
+                (render_gh_releases(&data.gh_releases))
+            }
+            section.panel.forge-panel data-panel="forge" aria-label="Forgejo" {
+                header.panel-header {
+                    span.source-icon.forge-icon aria-hidden="true" { (PreEscaped(include_str!("../static/icons/forgejo.svg"))) }
+                    a.source-name.forge-link href="https://codeberg.org/explore/repos" target="_blank" rel="noopener" { "Forgejo" }
+                    (render_tab_labels("forge", config::FORGE_SORTS, "Repository sort"))
+                    select.forge-select aria-label="Source" {
+                        @for (i, &(label, _, _)) in config::FORGE_SOURCES.iter().enumerate() {
+                            option value=(label) selected[i == 0] { (label) }
+                        }
+                    }
+                }
+                .forge-tabs {
+                    @for (i, &sort) in config::FORGE_SORTS.iter().enumerate() {
+                        .tab-content .active[i == 0]
+                            id=(format!("forge-{sort}"))
+                            role="tabpanel"
+                            aria-labelledby=(format!("forge-tab-{sort}"))
+                        {
+                            (render_forge_tab(&data.forge_repos, sort))
                         }
                     }
                 }
             }
-            section.panel.reddit-panel aria-label="Reddit" {
+            section.panel.reddit-panel data-panel="reddit" aria-label="Reddit" {
                 header.panel-header {
                     span.source-icon.reddit-icon aria-hidden="true" { (PreEscaped(include_str!("../static/icons/reddit.svg"))) }
                     a.source-name.reddit-link href="https://www.reddit.com" target="_blank" rel="noopener" { "Reddit" }
+                    select.reddit-sort-select aria-label="Sort" {
+                        @for (i, &(sort, _, _)) in config::REDDIT_SORTS.iter().enumerate() {
+                            option value=(sort) selected[i == 0] { (sort) }
+                        }
+                    }
                     select.subreddit-select aria-label="Subreddit" {
                         option value=(config::FILTER_ALL) selected { (config::FILTER_ALL) }
                         @for sub in config::REDDIT_SUBREDDITS {
-                            option value=(sub) { "r/" (sub) }
+                            option value=(sub) data-sorts=(reddit::sorts_for(sub).join(" ")) { "r/" (sub) }
+                        }
+                    }
+                }
+                (render_reddit_feed(&data.reddit_feed))
+            }
+            section.panel.lemmy-panel data-panel="lemmy" aria-label="Lemmy" {
+                header.panel-header {
+                    span.source-icon.lemmy-icon aria-hidden="true" { (PreEscaped(include_str!("../static/icons/lemmy.svg"))) }
+                    span.source-name { "Lemmy" }
+                    select.lemmy-select aria-label="Community" {
+                        option value=(config::FILTER_ALL) selected { (config::FILTER_ALL) }
+                        @for &community in config::LEMMY_COMMUNITIES {
+                            option value=(community.to_lowercase()) { "!" (community) }
+                        }
+                    }
+                }
+                (render_lemmy_feed(&data.lemmy_feed))
+            }
+            section.panel.tildes-panel data-panel="tildes" aria-label="Tildes" {
+                header.panel-header {
+                    span.source-icon.tildes-icon aria-hidden="true" { (PreEscaped(include_str!("../static/icons/tildes.svg"))) }
+                    a.source-name.tildes-link href="https://tildes.net/" target="_blank" rel="noopener" { "Tildes" }
+                    select.tildes-select aria-label="Group" {
+                        option value=(config::FILTER_ALL) selected { (config::FILTER_ALL) }
+                        @for &group in config::TILDES_GROUPS {
+                            option value=(group) { (group) }
+                        }
+                    }
+                }
+                (render_tildes_feed(&data.tildes_feed))
+            }
+            section.panel.mastodon-panel data-panel="mastodon" aria-label="Mastodon" {
+                header.panel-header {
+                    span.source-icon.mastodon-icon aria-hidden="true" { (PreEscaped(include_str!("../static/icons/mastodon.svg"))) }
+                    span.source-name { "Mastodon" }
+                    select.mastodon-select aria-label="Mastodon source" {
+                        option value=(config::FILTER_ALL) selected { (config::FILTER_ALL) }
+                        @for (instance, source) in config::MASTODON_SOURCES {
+                            @let label = mastodon::source_label(instance, source);
+                            option value=(label) { (label) }
+                        }
+                    }
+                }
+                (render_mastodon_feed(&data.mastodon_feed))
+            }
+            section.panel.bluesky-panel data-panel="bluesky" aria-label="Bluesky" {
+                header.panel-header {
+                    span.source-icon.bluesky-icon aria-hidden="true" { (PreEscaped(include_str!("../static/icons/bluesky.svg"))) }
+                    a.source-name.bluesky-link href="https://bsky.app/" target="_blank" rel="noopener" { "Bluesky" }
+                    select.bluesky-select aria-label="Bluesky feed" {
+                        option value=(config::FILTER_ALL) selected { (config::FILTER_ALL) }
+                        @for &(label, _) in config::BLUESKY_SOURCES {
+                            option value=(label) { (label) }
+                        }
+                    }
+                }
+                (render_bluesky_feed(&data.bluesky_feed))
+            }
+            section.panel.se-panel data-panel="se" aria-label="Stack Exchange" {
+                header.panel-header {
+                    span.source-icon.se-icon aria-hidden="true" { (PreEscaped(include_str!("../static/icons/stackexchange.svg"))) }
+                    a.source-name.se-link href="https://stackexchange.com/" target="_blank" rel="noopener" { "Stack Exchange" }
+                    (render_tab_labels("se", config::STACKEXCHANGE_SORTS, "Question sort"))
+                    select.se-select aria-label="Site" {
+                        option value=(config::FILTER_ALL) selected { (config::FILTER_ALL) }
+                        @for &(label, _, _) in config::STACKEXCHANGE_SITES {
+                            option value=(label) { (label) }
+                        }
+                    }
+                }
+                .se-tabs {
+                    @for (i, &sort) in config::STACKEXCHANGE_SORTS.iter().enumerate() {
+                        .tab-content .active[i == 0]
+                            id=(format!("se-{sort}"))
+                            role="tabpanel"
+                            aria-labelledby=(format!("se-tab-{sort}"))
+                        {
+                            (render_se_tab(&data.stackexchange_feed, sort))
+                        }
+                    }
+                }
+            }
+            section.panel.discourse-panel data-panel="discourse" aria-label="Discourse" {
+                header.panel-header {
+                    span.source-icon.discourse-icon aria-hidden="true" { (PreEscaped(include_str!("../static/icons/discourse.svg"))) }
+                    span.source-name { "Discourse" }
+                    (render_tab_labels("discourse", config::DISCOURSE_TABS, "Topic list"))
+                    select.discourse-select aria-label="Forum" {
+                        option value=(config::FILTER_ALL) selected { (config::FILTER_ALL) }
+                        @for &(label, _, _) in config::DISCOURSE_FORUMS {
+                            option value=(label) { (label) }
+                        }
+                    }
+                }
+                .discourse-tabs {
+                    @for (i, &tab) in config::DISCOURSE_TABS.iter().enumerate() {
+                        .tab-content .active[i == 0]
+                            id=(format!("discourse-{tab}"))
+                            role="tabpanel"
+                            aria-labelledby=(format!("discourse-tab-{tab}"))
+                        {
+                            (render_discourse_tab(&data.discourse_feed, tab))
+                        }
+                    }
+                }
+            }
+            section.panel.arxiv-panel data-panel="arxiv" aria-label="arXiv" {
+                header.panel-header {
+                    span.source-icon.arxiv-icon aria-hidden="true" { (PreEscaped(include_str!("../static/icons/arxiv.svg"))) }
+                    a.source-name.arxiv-link href="https://arxiv.org/" target="_blank" rel="noopener" { "arXiv" }
+                    select.arxiv-select aria-label="Category" {
+                        option value=(config::FILTER_ALL) selected { (config::FILTER_ALL) }
+                        @for &category in config::ARXIV_CATEGORIES {
+                            option value=(category) { (category) }
+                        }
+                    }
+                }
+                (render_arxiv_feed(&data.arxiv_feed))
+            }
+            section.panel.hf-panel data-panel="hf" aria-label="Hugging Face" {
+                header.panel-header {
+                    span.source-icon.hf-icon aria-hidden="true" { (PreEscaped(include_str!("../static/icons/huggingface.svg"))) }
+                    a.source-name.hf-link href="https://huggingface.co/" target="_blank" rel="noopener" { "Hugging Face" }
+                    (render_tab_labels("hf", config::HF_TABS, "Repository type"))
+                }
+                .hf-tabs {
+                    @for (i, &tab) in config::HF_TABS.iter().enumerate() {
+                        .tab-content .active[i == 0]
+                            id=(format!("hf-{tab}"))
+                            role="tabpanel"
+                            aria-labelledby=(format!("hf-tab-{tab}"))
+                        {
+                            (render_hf_tab(&data.hf_trending, tab))
+                        }
+                    }
+                }
+            }
+            section.panel.ph-panel data-panel="ph" aria-label="Product Hunt" {
+                @let ph_tabs: Vec<&str> = config::PRODUCTHUNT_TABS.iter().map(|&(tab, _)| tab).collect();
+                header.panel-header {
+                    span.source-icon.ph-icon aria-hidden="true" { (PreEscaped(include_str!("../static/icons/producthunt.svg"))) }
+                    a.source-name.ph-link href="https://www.producthunt.com/" target="_blank" rel="noopener" { "Product Hunt" }
+                    (render_tab_labels("ph", &ph_tabs, "Launch period"))
+                    select.ph-select aria-label="Topic" {
+                        option value=(config::FILTER_ALL) selected { (config::FILTER_ALL) }
+                        @for &(label, _) in config::PRODUCTHUNT_TOPICS {
+                            option value=(label) { (label) }
+                        }
+                    }
+                }
+                .ph-tabs {
+                    @for (i, &tab) in ph_tabs.iter().enumerate() {
+                        .tab-content .active[i == 0]
+                            id=(format!("ph-{tab}"))
+                            role="tabpanel"
+                            aria-labelledby=(format!("ph-tab-{tab}"))
+                        {
+                            (render_ph_tab(&data.producthunt_feed, tab))
+                        }
+                    }
+                }
+            }
+            section.panel.advisories-panel data-panel="advisories" aria-label="Security Advisories" {
+                header.panel-header {
+                    span.source-icon.advisories-icon aria-hidden="true" { (PreEscaped(include_str!("../static/icons/advisories.svg"))) }
+                    a.source-name.advisories-link href="https://github.com/advisories" target="_blank" rel="noopener" { "Advisories" }
+                    select.advisories-select aria-label="Ecosystem" {
+                        option value=(config::FILTER_ALL) selected { (config::FILTER_ALL) }
+                        @if data.advisory_feed.contains_key(config::ADVISORY_DEPS_VIEW) {
+                            option value=(config::ADVISORY_DEPS_VIEW) { (config::ADVISORY_DEPS_VIEW) }
+                        }
+                        @for &(label, _) in config::ADVISORY_ECOSYSTEMS {
+                            option value=(label) { (label) }
+                        }
+                    }
+                }
+                (render_advisory_feed(&data.advisory_feed))
+            }
+            section.panel.crates-panel data-panel="crates" aria-label="crates.io" {
+                header.panel-header {
+                    span.source-icon.crates-icon aria-hidden="true" { (PreEscaped(include_str!("../static/icons/crates.svg"))) }
+                    a.source-name.crates-link href="https://crates.io/" target="_blank" rel="noopener" { "crates.io" }
+                    (render_tab_labels("crates", config::CRATES_TABS, "Crate list"))
+                }
+                .crates-tabs {
+                    @for (i, &tab) in config::CRATES_TABS.iter().enumerate() {
+                        .tab-content .active[i == 0]
+                            id=(format!("crates-{tab}"))
+                            role="tabpanel"
+                            aria-labelledby=(format!("crates-tab-{tab}"))
+                        {
+                            (render_crates_tab(&data.crates_feed, tab))
+                        }
+                    }
+                }
+            }
+            @for &(panel, feeds) in config::FEED_PANELS {
+                section.panel.feed-panel data-panel=(format!("feed-{panel}")) aria-label=(panel) {
+                    header.panel-header {
+                        span.source-icon.feed-icon aria-hidden="true" { (PreEscaped(include_str!("../static/icons/feed.svg"))) }
+                        span.source-name { (panel) }
+                        select.feed-select aria-label="Feed" {
+                            option value=(config::FILTER_ALL) selected { (config::FILTER_ALL) }
+                            @for &(name, _) in feeds {
+                                option value=(name) { (name) }
+                            }
                         }
                     }
+                    (render_feed_panel(&data.feeds, panel, feeds))
+                }
+            }
+            @for &(panel, _, _) in config::COMMAND_PANELS {
+                @let command = data.command_feed.get(panel);
+                section.panel.command-panel data-panel=(format!("cmd-{panel}")) aria-label=(panel) {
+                    header.panel-header {
+                        span.source-icon.command-icon aria-hidden="true" { (PreEscaped(include_str!("../static/icons/command.svg"))) }
+                        span.source-name { (panel) }
+                        @if let Some(command) = command {
+                            (render_command_status(command))
+                        }
+                    }
+                    ol.command-items {
+                        li.empty-state { "no items" }
+                        @for item in command.map_or(&[][..], |c| c.items.as_slice()) {
+                            li.command-item data-id=[item.id.as_deref()] { (render_external_item(item)) }
+                        }
+                    }
+                }
+            }
+            @let ingest_feed = data.ingest_feed.load();
+            @for &(panel, _) in config::INGEST_PANELS {
+                section.panel.ingest-panel data-panel=(format!("push-{panel}")) aria-label=(panel) {
+                    header.panel-header {
+                        span.source-icon.ingest-icon aria-hidden="true" { (PreEscaped(include_str!("../static/icons/ingest.svg"))) }
+                        span.source-name { (panel) }
+                    }
+                    (render_ingest_items(ingest_feed.get(panel).map_or(&[], Vec::as_slice)))
                 }
-                (render_reddit_feed(reddit_feed))
             }
         }
         nav.swipe-dots aria-label="Panel navigation" {
-            button.swipe-dot.dot-hn.active type="button" aria-label="Hacker News" {}
-            button.swipe-dot.dot-gh type="button" aria-label="GitHub" {}
-            button.swipe-dot.dot-reddit type="button" aria-label="Reddit" {}
+            button.swipe-dot.dot-hn.active data-panel="hn" type="button" aria-label="Hacker News" {}
+            button.swipe-dot.dot-status data-panel="status" type="button" aria-label="Service Status" {}
+            button.swipe-dot.dot-jobs data-panel="jobs" type="button" aria-label="HN Jobs" {}
+            button.swipe-dot.dot-gh data-panel="gh" type="button" aria-label="GitHub" {}
+            button.swipe-dot.dot-forge data-panel="forge" type="button" aria-label="Forgejo" {}
+            button.swipe-dot.dot-reddit data-panel="reddit" type="button" aria-label="Reddit" {}
+            button.swipe-dot.dot-lemmy data-panel="lemmy" type="button" aria-label="Lemmy" {}
+            button.swipe-dot.dot-tildes data-panel="tildes" type="button" aria-label="Tildes" {}
+            button.swipe-dot.dot-mastodon data-panel="mastodon" type="button" aria-label="Mastodon" {}
+            button.swipe-dot.dot-bluesky data-panel="bluesky" type="button" aria-label="Bluesky" {}
+            button.swipe-dot.dot-se data-panel="se" type="button" aria-label="Stack Exchange" {}
+            button.swipe-dot.dot-discourse data-panel="discourse" type="button" aria-label="Discourse" {}
+            button.swipe-dot.dot-arxiv data-panel="arxiv" type="button" aria-label="arXiv" {}
+            button.swipe-dot.dot-hf data-panel="hf" type="button" aria-label="Hugging Face" {}
+            button.swipe-dot.dot-ph data-panel="ph" type="button" aria-label="Product Hunt" {}
+            button.swipe-dot.dot-advisories data-panel="advisories" type="button" aria-label="Security Advisories" {}
+            button.swipe-dot.dot-crates data-panel="crates" type="button" aria-label="crates.io" {}
+            @for &(panel, _) in config::FEED_PANELS {
+                button.swipe-dot.dot-feed data-panel=(format!("feed-{panel}")) type="button" aria-label=(panel) {}
+            }
+            @for &(panel, _, _) in config::COMMAND_PANELS {
+                button.swipe-dot.dot-command data-panel=(format!("cmd-{panel}")) type="button" aria-label=(panel) {}
+            }
+            @for &(panel, _) in config::INGEST_PANELS {
+                button.swipe-dot.dot-ingest data-panel=(format!("push-{panel}")) type="button" aria-label=(panel) {}
+            }
         }
-        @let fetched_ts = last_fetched.timestamp() as u64;
+        @let fetched_ts = data.last_fetched.timestamp() as u64;
         footer.last-updated aria-live="polite" {
             "updated "
             time.last-updated-time data-ts=(fetched_ts) { (format_time_ago(fetched_ts)) }
@@ -94,6 +432,42 @@ pub fn render_page(
     }).into_string()
 }
 
+/// Site page matching an HN list, for the panel header link.
+fn hn_page_url(name: &str, source: &HnSource) -> String {
+    match *source {
+        HnSource::Firebase(_) if name == "top" => "https://news.ycombinator.com/".to_string(),
+        HnSource::Firebase(_) => format!("https://news.ycombinator.com/{name}"),
+        HnSource::Algolia { window_secs, .. } => {
+            let range = match window_secs {
+                0..=86400 => "pastDay",
+                86401..=604800 => "pastWeek",
+                _ => "pastMonth",
+            };
+            format!("https://hn.algolia.com/?type=story&sort=byPopularity&dateRange={range}")
+        }
+    }
+}
+
+/// Radio-backed tab strip: `{prefix}-tab-{tab}` inputs controlling `{prefix}-{tab}` panels.
+/// Labels show the tab's first letter to keep the header compact.
+fn render_tab_labels(prefix: &str, tabs: &[&str], aria_label: &str) -> Markup {
+    html! {
+        .tab-labels role="tablist" aria-label=(aria_label) {
+            @for (i, &tab) in tabs.iter().enumerate() {
+                input id=(format!("{prefix}-tab-{tab}"))
+                      type="radio" name=(format!("{prefix}-tab"))
+                      checked[i == 0];
+                label .active[i == 0]
+                      for=(format!("{prefix}-tab-{tab}"))
+                      role="tab"
+                      aria-selected=(if i == 0 { "true" } else { "false" })
+                      aria-controls=(format!("{prefix}-{tab}"))
+                      { (&tab[..1]) }
+            }
+        }
+    }
+}
+
 // Item renderers
 
 fn render_hn_story(story: &HnStory) -> Markup {
@@ -123,6 +497,75 @@ fn render_hn_story(story: &HnStory) -> Markup {
     }
 }
 
+fn render_service_status(service: &ServiceStatus) -> Markup {
+    html! {
+        span.status-title {
+            a href=(service.url) { (service.name) }
+            span class=(format!("status-indicator ind-{}", service.indicator.as_str())) {
+                (service.indicator.as_str())
+            }
+        }
+        @for incident in &service.incidents {
+            @let ts = incident.at.timestamp() as u64;
+            div.status-event {
+                a href=(incident.url) { (incident.name) }
+                @if !incident.status.is_empty() {
+                    span.sep { (SEP) }
+                    span.status-event-state { (incident.status.replace('_', " ")) }
+                }
+                span.sep { (SEP) }
+                time.time-ago data-ts=(ts) { (format_time_ago(ts)) }
+            }
+        }
+        @for maintenance in &service.maintenances {
+            div.status-event.status-maintenance {
+                "maintenance: "
+                a href=(maintenance.url) { (maintenance.name) }
+                span.sep { (SEP) }
+                (maintenance.at.format("%b %-d %H:%M UTC"))
+            }
+        }
+        div.status-meta {
+            span.status-dot {}
+            (service.description)
+            @if !service.degraded_components.is_empty() {
+                span.sep { (SEP) }
+                span.status-components { (service.degraded_components.join(", ")) }
+            }
+        }
+    }
+}
+
+fn render_job(job: &Job) -> Markup {
+    html! {
+        span.job-title {
+            a href=(job.url) { (job.company) }
+            @if let Some(ref role) = job.role {
+                span.job-role { (role) }
+            }
+        }
+        @if !job.summary.is_empty() {
+            p.repo-desc { (job.summary) }
+        }
+        div.job-meta {
+            span.jobs-dot {}
+            @if job.workplace != Workplace::Unknown {
+                span.job-workplace { (job.workplace.as_str()) }
+                span.sep { (SEP) }
+            }
+            @if let Some(ref location) = job.location {
+                span.job-location { (location) }
+                span.sep { (SEP) }
+            }
+            @if let Some(ref stack) = job.stack {
+                span.job-stack { (stack) }
+                span.sep { (SEP) }
+            }
+            time.time-ago data-ts=(job.posted) { (format_time_ago(job.posted)) }
+        }
+    }
+}
+
 fn render_gh_repo(repo: &TrendingRepo) -> Markup {
     html! {
         span.repo-title {
@@ -149,6 +592,67 @@ fn render_gh_repo(repo: &TrendingRepo) -> Markup {
                 span.sep { (SEP) }
                 span.period-stars { (&repo.period_stars) }
             }
+            @if !repo.contributors.is_empty() {
+                span.sep { (SEP) }
+                span.repo-built-by {
+                    "by "
+                    @for (i, login) in repo.contributors.iter().enumerate() {
+                        @if i > 0 { ", " }
+                        a href=(format!("https://github.com/{login}")) { (login) }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn render_gh_developer(dev: &TrendingDeveloper) -> Markup {
+    html! {
+        span.repo-title {
+            a href=(dev.url) { (&dev.name) }
+            @if dev.name != dev.login {
+                span.gh-dev-login { "@" (&dev.login) }
+            }
+        }
+        @if let Some(ref repo) = dev.popular_repo {
+            @if !repo.description.is_empty() {
+                p.repo-desc { (&repo.description) }
+            }
+            div.repo-meta {
+                span.gh-dev-popular { "popular repo" }
+                span.sep { (SEP) }
+                a.gh-dev-repo href=(repo.url) { (&repo.name) }
+            }
+        }
+    }
+}
+
+fn render_gh_release(release: &GhRelease) -> Markup {
+    let published_ts = release.published.timestamp() as u64;
+    let (owner, name) = release.repo.split_once('/').unwrap_or(("", &release.repo));
+    html! {
+        span.release-title {
+            a href=(release.url) {
+                span.repo-author { (owner) "/" }
+                (name)
+                " "
+                span.release-tag { (&release.tag) }
+            }
+            @if release.major_bump {
+                span.release-major title="Major version bump" { "major" }
+            }
+        }
+        div.release-meta {
+            span.releases-dot {}
+            @if release.name != release.tag {
+                span.release-name { (&release.name) }
+                span.sep { (SEP) }
+            }
+            @if release.prerelease {
+                span.release-pre { "pre-release" }
+                span.sep { (SEP) }
+            }
+            time.time-ago data-ts=(published_ts) { (format_time_ago(published_ts)) }
         }
     }
 }
@@ -164,14 +668,17 @@ fn render_reddit_post(post: &RedditPost) -> Markup {
         }
         div.reddit-post-meta {
             span.reddit-dot {}
-            span.reddit-score { (post.score) " pts" }
-            span.sep { (SEP) }
+            // RSS carries no score or comment count; don't show them as zero.
+            @if post.source != RedditSource::Rss {
+                span.reddit-score { (post.score) " pts" }
+                span.sep { (SEP) }
+            }
             (post.author)
             span.sep { (SEP) }
             time.time-ago data-ts=(created_ts) { (format_time_ago(created_ts)) }
             span.sep { (SEP) }
             a href=(post.permalink) {
-                (post.comment_count) " comments"
+                @if post.source == RedditSource::Rss { "comments" } @else { (post.comment_count) " comments" }
             }
             @if !post.is_self {
                 span.sep { (SEP) }
@@ -181,6 +688,325 @@ fn render_reddit_post(post: &RedditPost) -> Markup {
     }
 }
 
+fn render_lemmy_post(post: &LemmyPost) -> Markup {
+    let created_ts = post.created_at.timestamp() as u64;
+    html! {
+        span.lemmy-post-title {
+            a href=(post.url) {
+                (post.title)
+            }
+            span.lemmy-community { "!" (post.community) }
+        }
+        div.lemmy-post-meta {
+            span.lemmy-dot {}
+            span.lemmy-score { (post.score) " pts" }
+            span.sep { (SEP) }
+            (post.author)
+            span.sep { (SEP) }
+            time.time-ago data-ts=(created_ts) { (format_time_ago(created_ts)) }
+            span.sep { (SEP) }
+            a href=(post.post_url) {
+                (post.comment_count) " comments"
+            }
+            @if !post.is_self {
+                span.sep { (SEP) }
+                span.lemmy-domain { (post.domain) }
+            }
+        }
+    }
+}
+
+fn render_tildes_topic(topic: &TildesTopic) -> Markup {
+    let posted_ts = topic.posted_at.timestamp() as u64;
+    html! {
+        span.tildes-topic-title {
+            a href=(topic.url) {
+                (topic.title)
+            }
+            span.tildes-group { (topic.group) }
+        }
+        div.tildes-topic-meta {
+            span.tildes-dot {}
+            span.tildes-score { (topic.votes) " votes" }
+            span.sep { (SEP) }
+            (topic.author)
+            span.sep { (SEP) }
+            time.time-ago data-ts=(posted_ts) { (format_time_ago(posted_ts)) }
+            span.sep { (SEP) }
+            a href=(topic.topic_url) {
+                (topic.comment_count) " comments"
+            }
+            @if !topic.tags.is_empty() {
+                span.sep { (SEP) }
+                span.tildes-tags { (topic.tags.join(", ")) }
+            }
+        }
+    }
+}
+
+fn render_mastodon_post(post: &MastodonPost) -> Markup {
+    let created_ts = post.created_at.timestamp() as u64;
+    html! {
+        span.mastodon-post-title {
+            a href=(post.url) { (post.text) }
+        }
+        div.mastodon-post-meta {
+            span.mastodon-dot {}
+            span.mastodon-score { (post.score()) " pts" }
+            span.sep { (SEP) }
+            (post.boosts) " boosts"
+            span.sep { (SEP) }
+            (post.favourites) " favs"
+            span.sep { (SEP) }
+            "@" (post.author)
+            span.sep { (SEP) }
+            time.time-ago data-ts=(created_ts) { (format_time_ago(created_ts)) }
+            span.sep { (SEP) }
+            a href=(post.url) { (post.replies) " replies" }
+        }
+    }
+}
+
+fn render_bluesky_post(post: &BlueskyPost) -> Markup {
+    let created_ts = post.created_at.timestamp() as u64;
+    html! {
+        span.bluesky-post-title {
+            a href=(post.url) { (post.text) }
+        }
+        div.bluesky-post-meta {
+            span.bluesky-dot {}
+            span.bluesky-likes { (post.likes) " likes" }
+            span.sep { (SEP) }
+            (post.reposts) " reposts"
+            span.sep { (SEP) }
+            "@" (post.handle)
+            span.sep { (SEP) }
+            time.time-ago data-ts=(created_ts) { (format_time_ago(created_ts)) }
+            span.sep { (SEP) }
+            a href=(post.url) { (post.replies) " replies" }
+        }
+    }
+}
+
+fn render_se_question(q: &StackQuestion) -> Markup {
+    let created_ts = q.creation_date.timestamp() as u64;
+    html! {
+        span.se-question-title {
+            a href=(q.link) { (q.title) }
+            span.se-site { (q.site) }
+        }
+        div.se-question-meta {
+            span.se-dot {}
+            span.se-score { (q.score) " votes" }
+            span.sep { (SEP) }
+            @if q.is_accepted() {
+                span.se-accepted title="Accepted answer" { "\u{2713} " (q.answer_count) " answers" }
+            } @else {
+                span { (q.answer_count) " answers" }
+            }
+            span.sep { (SEP) }
+            time.time-ago data-ts=(created_ts) { (format_time_ago(created_ts)) }
+            @for tag in q.tags.iter().take(3) {
+                span.sep { (SEP) }
+                span.se-tag { (tag) }
+            }
+        }
+    }
+}
+
+fn render_discourse_topic(topic: &DiscourseTopic) -> Markup {
+    let activity_ts = topic.last_activity.timestamp() as u64;
+    html! {
+        span.discourse-topic-title {
+            a href=(topic.url) { (topic.title) }
+            span.discourse-forum { (topic.forum) }
+        }
+        div.discourse-topic-meta {
+            span.discourse-dot {}
+            span.discourse-replies { (topic.replies) " replies" }
+            span.sep { (SEP) }
+            (topic.likes) " likes"
+            span.sep { (SEP) }
+            (fmt_num(topic.views)) " views"
+            span.sep { (SEP) }
+            time.time-ago data-ts=(activity_ts) { (format_time_ago(activity_ts)) }
+        }
+    }
+}
+
+fn render_advisory(advisory: &Advisory) -> Markup {
+    let published_ts = advisory.published_at.timestamp() as u64;
+    html! {
+        span.advisory-title {
+            a href=(advisory.html_url) { (advisory.summary) }
+        }
+        div.advisory-meta {
+            span.advisories-dot {}
+            span class=(format!("advisory-severity sev-{}", advisory.severity)) { (advisory.severity) }
+            span.sep { (SEP) }
+            span.advisory-packages {
+                (advisory.ecosystem) ": " (advisory.packages().join(", "))
+            }
+            span.sep { (SEP) }
+            (advisory.cve_id.as_deref().unwrap_or(&advisory.ghsa_id))
+            span.sep { (SEP) }
+            time.time-ago data-ts=(published_ts) { (format_time_ago(published_ts)) }
+        }
+    }
+}
+
+fn render_arxiv_paper(paper: &ArxivPaper) -> Markup {
+    let published_ts = paper.published.timestamp() as u64;
+    html! {
+        span.arxiv-paper-title {
+            a href=(paper.abs_url) { (paper.title) }
+            span.arxiv-category { (paper.primary_category) }
+        }
+        @if !paper.summary.is_empty() {
+            p.repo-desc { (paper.summary) }
+        }
+        div.arxiv-paper-meta {
+            span.arxiv-dot {}
+            span.arxiv-authors {
+                (paper.authors.join(", "))
+                @if paper.author_count > paper.authors.len() { " et al." }
+            }
+            span.sep { (SEP) }
+            time.time-ago data-ts=(published_ts) { (format_time_ago(published_ts)) }
+            span.sep { (SEP) }
+            a href=(paper.pdf_url) { "pdf" }
+        }
+    }
+}
+
+fn render_crate(krate: &CrateInfo) -> Markup {
+    let updated_ts = krate.updated_at.timestamp() as u64;
+    html! {
+        span.crate-title {
+            a href=(krate.url()) { (krate.name) }
+            @if !krate.version.is_empty() {
+                span.crate-version { "v" (krate.version) }
+            }
+        }
+        @if let Some(desc) = krate.description.as_deref().map(str::trim).filter(|d| !d.is_empty()) {
+            p.repo-desc { (desc) }
+        }
+        div.crate-meta {
+            span.crate-downloads { "\u{2193} " (fmt_num(krate.downloads)) }
+            // crates.io's rolling 90-day total, not a change since the last scrape.
+            @if let Some(recent) = krate.recent_downloads {
+                span.sep { (SEP) }
+                span.crate-recent { (fmt_num(recent)) " 90d downloads" }
+            }
+            span.sep { (SEP) }
+            time.time-ago data-ts=(updated_ts) { (format_time_ago(updated_ts)) }
+        }
+    }
+}
+
+fn render_hf_repo(repo: &HfRepo) -> Markup {
+    html! {
+        span.hf-title {
+            a href=(repo.url) { (repo.id) }
+            @if let Some(ref task) = repo.task {
+                span.hf-task { (task) }
+            }
+        }
+        div.hf-meta {
+            span.hf-dot {}
+            span.hf-likes { "\u{2665} " (fmt_num(repo.likes)) }
+            @if let Some(downloads) = repo.downloads {
+                span.sep { (SEP) }
+                span.hf-downloads { "\u{2193} " (fmt_num(downloads)) }
+            }
+            @if let Some(modified) = repo.last_modified {
+                @let modified_ts = modified.timestamp() as u64;
+                span.sep { (SEP) }
+                time.time-ago data-ts=(modified_ts) { (format_time_ago(modified_ts)) }
+            }
+        }
+    }
+}
+
+fn render_ph_launch(launch: &ProductLaunch) -> Markup {
+    let created_ts = launch.created_at.timestamp() as u64;
+    html! {
+        span.ph-launch-title {
+            a href=(launch.url) { (launch.name) }
+        }
+        @if !launch.tagline.is_empty() {
+            p.repo-desc { (launch.tagline) }
+        }
+        div.ph-launch-meta {
+            span.ph-dot {}
+            span.ph-score { "\u{25B2} " (fmt_num(launch.votes)) }
+            span.sep { (SEP) }
+            time.time-ago data-ts=(created_ts) { (format_time_ago(created_ts)) }
+            span.sep { (SEP) }
+            a href=(launch.url) { (launch.comment_count) " comments" }
+        }
+    }
+}
+
+fn render_feed_item(item: &FeedItem) -> Markup {
+    html! {
+        span.feed-item-title {
+            a href=(item.link) { (item.title) }
+            span.feed-item-source { (item.feed) }
+        }
+        div.feed-item-meta {
+            span.feed-dot {}
+            @if let Some(ref author) = item.author {
+                (author)
+                span.sep { (SEP) }
+            }
+            time.time-ago data-ts=(item.published) { (format_time_ago(item.published)) }
+        }
+    }
+}
+
+fn render_external_item(item: &ExternalItem) -> Markup {
+    html! {
+        span.command-item-title {
+            @match item.url {
+                Some(ref url) => a href=(url) { (item.title) },
+                None => (item.title),
+            }
+        }
+        div.command-item-meta {
+            span.command-dot {}
+            @if let Some(score) = item.score {
+                span.command-score { (score) }
+                span.sep { (SEP) }
+            }
+            @if let Some(ref author) = item.author {
+                (author)
+                span.sep { (SEP) }
+            }
+            @if let Some(ts) = item.timestamp {
+                time.time-ago data-ts=(ts) { (format_time_ago(ts)) }
+            }
+        }
+    }
+}
+
+/// A failed run keeps the previous items; the header says so, with stderr on hover.
+fn render_command_status(command: &CommandPanel) -> Markup {
+    let status = &command.status;
+    let ran_ts = status.ran_at.timestamp() as u64;
+    let detail = if status.stderr.is_empty() {
+        status.message.clone()
+    } else {
+        format!("{}\n{}", status.message, status.stderr)
+    };
+    html! {
+        span.command-status.failed[!status.ok] title=(detail) {
+            @if status.ok { "ran " } @else { "failed " }
+            time.time-ago data-ts=(ran_ts) { (format_time_ago(ran_ts)) }
+        }
+    }
+}
+
 // Collection renderers
 
 fn render_hn_pages(hn_pages: &HnPages) -> Markup {
@@ -196,12 +1022,66 @@ fn render_hn_pages(hn_pages: &HnPages) -> Markup {
     }
 }
 
-fn render_gh_trending(trending: &GhTrending, period: &str) -> Markup {
-    let lang_keys = std::iter::once(config::FILTER_ALL)
-        .chain(config::GITHUB_LANGUAGES.iter().map(|&(name, _)| name));
+/// Worst indicator first, config order otherwise.
+fn render_status_feed(feed: &StatusFeed) -> Markup {
+    let mut services: Vec<&ServiceStatus> = feed.iter().collect();
+    services.sort_by_key(|s| std::cmp::Reverse(s.indicator));
+
+    html! {
+        ol.status-services {
+            li.empty-state { "no services" }
+            @for service in services {
+                li.status-service.degraded[service.indicator.is_degraded()] {
+                    (render_service_status(service))
+                }
+            }
+        }
+    }
+}
+
+/// Shown above the panels only while some service is degraded.
+fn render_status_banner(feed: &StatusFeed) -> Markup {
+    let degraded: Vec<&ServiceStatus> = feed.iter().filter(|s| s.indicator.is_degraded()).collect();
+    html! {
+        @if !degraded.is_empty() {
+            .status-banner role="status" {
+                "degraded: "
+                @for (i, service) in degraded.iter().enumerate() {
+                    @if i > 0 { (SEP) }
+                    a href=(service.url) target="_blank" rel="noopener" {
+                        (service.name) " (" (service.description) ")"
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// One list; workplace, location and tech filters match `data-*` on each job.
+fn render_jobs(feed: &HiringFeed) -> Markup {
+    html! {
+        ol.jobs {
+            li.empty-state { "no jobs" }
+            @for job in feed.values().flatten() {
+                li.job
+                    data-workplace=(job.workplace.as_str())
+                    data-regions=(job.regions.join("|"))
+                    data-tech=(job.tech.join("|"))
+                    { (render_job(job)) }
+            }
+        }
+    }
+}
+
+/// Repo lists first so the no-JS default (`:first-of-type`) stays the repos view.
+fn render_gh_trending(trending: &GhTrending, developers: &GhDevelopers, period: &str) -> Markup {
+    let lang_keys = || {
+        std::iter::once(config::FILTER_ALL)
+            .chain(config::GITHUB_LANGUAGES.iter().map(|&(name, _)| name))
+    };
 
     html! {
-        @for key in lang_keys {
+        @for key in lang_keys() {
             ol.repos data-for-lang=(key.to_lowercase()) {
                 li.empty-state { "no repos" }
                 @let lookup = (period.to_string(), key.to_string());
@@ -210,20 +1090,283 @@ fn render_gh_trending(trending: &GhTrending, period: &str) -> Markup {
                 }
             }
         }
+        @for key in lang_keys() {
+            ol.gh-devs data-for-lang=(key.to_lowercase()) {
+                li.empty-state { "no developers" }
+                @let lookup = (period.to_string(), key.to_string());
+                @for dev in developers.get(&lookup).into_iter().flatten() {
+                    li.repo.gh-dev { (render_gh_developer(dev)) }
+                }
+            }
+        }
+    }
+}
+
+fn render_forge_tab(feed: &ForgeRepos, sort: &str) -> Markup {
+    html! {
+        @for &(label, _, _) in config::FORGE_SOURCES {
+            ol.repos data-for-source=(label) {
+                li.empty-state { "no repos" }
+                @let lookup = (sort.to_string(), label.to_string());
+                @for repo in feed.get(&lookup).into_iter().flatten() {
+                    li.repo { (render_gh_repo(repo)) }
+                }
+            }
+        }
+    }
+}
+
+fn render_gh_releases(feed: &GhReleases) -> Markup {
+    let repos =
+        std::iter::once(config::FILTER_ALL).chain(config::GITHUB_RELEASE_REPOS.iter().copied());
+
+    html! {
+        @for repo in repos {
+            ol.releases data-for-repo=(repo) {
+                li.empty-state { "no releases" }
+                @for release in feed.get(repo).into_iter().flatten() {
+                    li.release.major-bump[release.major_bump] { (render_gh_release(release)) }
+                }
+            }
+        }
     }
 }
 
+/// One list per (subreddit, sort) that is actually fetched, plus an "all" list per sort.
 fn render_reddit_feed(feed: &RedditFeed) -> Markup {
-    let subs = std::iter::once(config::FILTER_ALL).chain(config::REDDIT_SUBREDDITS.iter().copied());
+    html! {
+        @for &(sort, _, _) in config::REDDIT_SORTS {
+            @let subs = std::iter::once(config::FILTER_ALL).chain(
+                config::REDDIT_SUBREDDITS
+                    .iter()
+                    .copied()
+                    .filter(|sub| reddit::sorts_for(sub).contains(&sort)),
+            );
+            @for sub in subs {
+                ol.reddit-posts data-for-sub=(sub) data-for-sort=(sort) {
+                    li.empty-state { "no posts" }
+                    @let lookup = (sub.to_lowercase(), sort.to_string());
+                    @for post in feed.get(&lookup).into_iter().flatten() {
+                        li.reddit-post data-sub=(post.subreddit) data-source=(post.source.as_str()) { (render_reddit_post(post)) }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn render_lemmy_feed(feed: &LemmyFeed) -> Markup {
+    let keys = std::iter::once(config::FILTER_ALL.to_string()).chain(
+        config::LEMMY_COMMUNITIES
+            .iter()
+            .map(|community| community.to_lowercase()),
+    );
+
+    html! {
+        @for key in keys {
+            ol.lemmy-posts data-for-community=(key) {
+                li.empty-state { "no posts" }
+                @for post in feed.get(&key).into_iter().flatten() {
+                    li.lemmy-post { (render_lemmy_post(post)) }
+                }
+            }
+        }
+    }
+}
+
+fn render_tildes_feed(feed: &TildesFeed) -> Markup {
+    let keys = std::iter::once(config::FILTER_ALL).chain(config::TILDES_GROUPS.iter().copied());
+
+    html! {
+        @for key in keys {
+            ol.tildes-topics data-for-group=(key) {
+                li.empty-state { "no topics" }
+                @for topic in feed.get(key).into_iter().flatten() {
+                    li.tildes-topic { (render_tildes_topic(topic)) }
+                }
+            }
+        }
+    }
+}
+
+fn render_mastodon_feed(feed: &MastodonFeed) -> Markup {
+    let keys = std::iter::once(config::FILTER_ALL.to_string()).chain(
+        config::MASTODON_SOURCES
+            .iter()
+            .map(|(instance, source)| mastodon::source_label(instance, source)),
+    );
+
+    html! {
+        @for key in keys {
+            ol.mastodon-posts data-for-source=(key) {
+                li.empty-state { "no posts" }
+                @for post in feed.get(&key).into_iter().flatten() {
+                    li.mastodon-post { (render_mastodon_post(post)) }
+                }
+            }
+        }
+    }
+}
+
+fn render_bluesky_feed(feed: &BlueskyFeed) -> Markup {
+    let keys = std::iter::once(config::FILTER_ALL)
+        .chain(config::BLUESKY_SOURCES.iter().map(|&(label, _)| label));
 
     html! {
-        @for sub in subs {
-            ol.reddit-posts data-for-sub=(sub) {
+        @for key in keys {
+            ol.bluesky-posts data-for-source=(key) {
                 li.empty-state { "no posts" }
-                @for post in feed.get(sub).into_iter().flatten() {
-                    li.reddit-post data-sub=(post.subreddit) { (render_reddit_post(post)) }
+                @for post in feed.get(key).into_iter().flatten() {
+                    li.bluesky-post { (render_bluesky_post(post)) }
                 }
             }
         }
     }
 }
+
+fn render_se_tab(feed: &StackExchangeFeed, sort: &str) -> Markup {
+    let sites = std::iter::once(config::FILTER_ALL).chain(
+        config::STACKEXCHANGE_SITES
+            .iter()
+            .map(|&(label, _, _)| label),
+    );
+
+    html! {
+        @for site in sites {
+            ol.se-questions data-for-site=(site) {
+                li.empty-state { "no questions" }
+                @let lookup = (site.to_string(), sort.to_string());
+                @for q in feed.get(&lookup).into_iter().flatten() {
+                    li.se-question { (render_se_question(q)) }
+                }
+            }
+        }
+    }
+}
+
+fn render_discourse_tab(feed: &DiscourseFeed, tab: &str) -> Markup {
+    let forums = std::iter::once(config::FILTER_ALL)
+        .chain(config::DISCOURSE_FORUMS.iter().map(|&(label, _, _)| label));
+
+    html! {
+        @for forum in forums {
+            ol.discourse-topics data-for-forum=(forum) {
+                li.empty-state { "no topics" }
+                @let lookup = (forum.to_string(), tab.to_string());
+                @for topic in feed.get(&lookup).into_iter().flatten() {
+                    li.discourse-topic { (render_discourse_topic(topic)) }
+                }
+            }
+        }
+    }
+}
+
+fn render_advisory_feed(feed: &AdvisoryFeed) -> Markup {
+    let keys = [config::FILTER_ALL, config::ADVISORY_DEPS_VIEW]
+        .into_iter()
+        .chain(config::ADVISORY_ECOSYSTEMS.iter().map(|&(label, _)| label));
+
+    html! {
+        @for key in keys {
+            ol.advisories data-for-ecosystem=(key) {
+                li.empty-state { "no advisories" }
+                @for advisory in feed.get(key).into_iter().flatten() {
+                    li.advisory { (render_advisory(advisory)) }
+                }
+            }
+        }
+    }
+}
+
+fn render_arxiv_feed(feed: &ArxivFeed) -> Markup {
+    let keys = std::iter::once(config::FILTER_ALL).chain(config::ARXIV_CATEGORIES.iter().copied());
+
+    html! {
+        @for key in keys {
+            ol.arxiv-papers data-for-category=(key) {
+                li.empty-state { "no papers" }
+                @for paper in feed.get(key).into_iter().flatten() {
+                    li.arxiv-paper { (render_arxiv_paper(paper)) }
+                }
+            }
+        }
+    }
+}
+
+/// Watchlist crates are pinned above every tab; they are skipped further down
+/// so a watched crate that also trends is not listed twice.
+fn render_crates_tab(feed: &CratesFeed, tab: &str) -> Markup {
+    let watchlist = feed
+        .get(crates::WATCHLIST_KEY)
+        .map_or(&[][..], Vec::as_slice);
+    html! {
+        ol.crates {
+            li.empty-state { "no crates" }
+            @for krate in watchlist {
+                li.crate.crate-watched { (render_crate(krate)) }
+            }
+            @for krate in feed.get(tab).into_iter().flatten() {
+                @if !watchlist.iter().any(|w| w.name == krate.name) {
+                    li.crate { (render_crate(krate)) }
+                }
+            }
+        }
+    }
+}
+
+fn render_hf_tab(feed: &HfTrending, tab: &str) -> Markup {
+    html! {
+        ol.hf-repos {
+            li.empty-state { "no repositories" }
+            @for repo in feed.get(tab).into_iter().flatten() {
+                li.hf-repo { (render_hf_repo(repo)) }
+            }
+        }
+    }
+}
+
+fn render_ph_tab(feed: &ProductHuntFeed, tab: &str) -> Markup {
+    let topics = std::iter::once(config::FILTER_ALL)
+        .chain(config::PRODUCTHUNT_TOPICS.iter().map(|&(label, _)| label));
+
+    html! {
+        @for topic in topics {
+            ol.ph-launches data-for-topic=(topic) {
+                li.empty-state { "no launches" }
+                @let lookup = (tab.to_string(), topic.to_string());
+                @for launch in feed.get(&lookup).into_iter().flatten() {
+                    li.ph-launch { (render_ph_launch(launch)) }
+                }
+            }
+        }
+    }
+}
+
+fn render_feed_panel(data: &Feeds, panel: &str, feeds: &[(&str, &str)]) -> Markup {
+    let keys = std::iter::once(config::FILTER_ALL).chain(feeds.iter().map(|&(name, _)| name));
+
+    html! {
+        @for key in keys {
+            ol.feed-items data-for-feed=(key) {
+                li.empty-state { "no items" }
+                @let lookup = (panel.to_string(), key.to_string());
+                @for item in data.get(&lookup).into_iter().flatten() {
+                    li.feed-item { (render_feed_item(item)) }
+                }
+            }
+        }
+    }
+}
+
+/// Expired items stay in the feed until the next push prunes them.
+fn render_ingest_items(items: &[IngestedItem]) -> Markup {
+    let now = Utc::now().timestamp() as u64;
+    html! {
+        ol.command-items {
+            li.empty-state { "no items" }
+            @for pushed in items.iter().filter(|i| i.is_live(now)) {
+                li.command-item data-id=[pushed.item.id.as_deref()] { (render_external_item(&pushed.item)) }
+            }
+        }
+    }
+}
@@ -52,14 +52,88 @@ pub fn render_settings_page() -> String {
                                     (PreEscaped(include_str!("../static/icons/hn.svg")))
                                     "hackernews"
                                 }
+                                button.panel-toggle.active data-panel="status" {
+                                    (PreEscaped(include_str!("../static/icons/status.svg")))
+                                    "status"
+                                }
+                                button.panel-toggle.active data-panel="jobs" {
+                                    (PreEscaped(include_str!("../static/icons/jobs.svg")))
+                                    "jobs"
+                                }
                                 button.panel-toggle.active data-panel="gh" {
                                     (PreEscaped(include_str!("../static/icons/github.svg")))
                                     "github"
                                 }
+                                button.panel-toggle.active data-panel="forge" {
+                                    (PreEscaped(include_str!("../static/icons/forgejo.svg")))
+                                    "forgejo"
+                                }
                                 button.panel-toggle.active data-panel="reddit" {
                                     (PreEscaped(include_str!("../static/icons/reddit.svg")))
                                     "reddit"
                                 }
+                                button.panel-toggle.active data-panel="lemmy" {
+                                    (PreEscaped(include_str!("../static/icons/lemmy.svg")))
+                                    "lemmy"
+                                }
+                                button.panel-toggle.active data-panel="tildes" {
+                                    (PreEscaped(include_str!("../static/icons/tildes.svg")))
+                                    "tildes"
+                                }
+                                button.panel-toggle.active data-panel="mastodon" {
+                                    (PreEscaped(include_str!("../static/icons/mastodon.svg")))
+                                    "mastodon"
+                                }
+                                button.panel-toggle.active data-panel="bluesky" {
+                                    (PreEscaped(include_str!("../static/icons/bluesky.svg")))
+                                    "bluesky"
+                                }
+                                button.panel-toggle.active data-panel="se" {
+                                    (PreEscaped(include_str!("../static/icons/stackexchange.svg")))
+                                    "stack exchange"
+                                }
+                                button.panel-toggle.active data-panel="discourse" {
+                                    (PreEscaped(include_str!("../static/icons/discourse.svg")))
+                                    "discourse"
+                                }
+                                button.panel-toggle.active data-panel="arxiv" {
+                                    (PreEscaped(include_str!("../static/icons/arxiv.svg")))
+                                    "arxiv"
+                                }
+                                button.panel-toggle.active data-panel="hf" {
+                                    (PreEscaped(include_str!("../static/icons/huggingface.svg")))
+                                    "hugging face"
+                                }
+                                button.panel-toggle.active data-panel="ph" {
+                                    (PreEscaped(include_str!("../static/icons/producthunt.svg")))
+                                    "product hunt"
+                                }
+                                button.panel-toggle.active data-panel="advisories" {
+                                    (PreEscaped(include_str!("../static/icons/advisories.svg")))
+                                    "advisories"
+                                }
+                                button.panel-toggle.active data-panel="crates" {
+                                    (PreEscaped(include_str!("../static/icons/crates.svg")))
+                                    "crates.io"
+                                }
+                                @for &(panel, _) in config::FEED_PANELS {
+                                    button.panel-toggle.active data-panel=(format!("feed-{panel}")) {
+                                        (PreEscaped(include_str!("../static/icons/feed.svg")))
+                                        (panel)
+                                    }
+                                }
+                                @for &(panel, _, _) in config::COMMAND_PANELS {
+                                    button.panel-toggle.active data-panel=(format!("cmd-{panel}")) {
+                                        (PreEscaped(include_str!("../static/icons/command.svg")))
+                                        (panel)
+                                    }
+                                }
+                                @for &(panel, _) in config::INGEST_PANELS {
+                                    button.panel-toggle.active data-panel=(format!("push-{panel}")) {
+                                        (PreEscaped(include_str!("../static/icons/ingest.svg")))
+                                        (panel)
+                                    }
+                                }
                             }
                         }
 
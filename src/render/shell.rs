@@ -46,15 +46,97 @@ pub fn render_loading_page() -> String {
                         "hackernews"
                     }
                     span.loading-sep { (SEP) }
+                    span.loading-src.status-accent {
+                        (PreEscaped(include_str!("../static/icons/status.svg")))
+                        "status"
+                    }
+                    span.loading-sep { (SEP) }
+                    span.loading-src.jobs-accent {
+                        (PreEscaped(include_str!("../static/icons/jobs.svg")))
+                        "jobs"
+                    }
+                    span.loading-sep { (SEP) }
                     span.loading-src.gh-accent {
                         (PreEscaped(include_str!("../static/icons/github.svg")))
                         "github"
                     }
                     span.loading-sep { (SEP) }
+                    span.loading-src.forge-accent {
+                        (PreEscaped(include_str!("../static/icons/forgejo.svg")))
+                        "forgejo"
+                    }
+                    span.loading-sep { (SEP) }
                     span.loading-src.reddit-accent {
                         (PreEscaped(include_str!("../static/icons/reddit.svg")))
                         "reddit"
                     }
+                    span.loading-sep { (SEP) }
+                    span.loading-src.lemmy-accent {
+                        (PreEscaped(include_str!("../static/icons/lemmy.svg")))
+                        "lemmy"
+                    }
+                    span.loading-sep { (SEP) }
+                    span.loading-src.tildes-accent {
+                        (PreEscaped(include_str!("../static/icons/tildes.svg")))
+                        "tildes"
+                    }
+                    span.loading-sep { (SEP) }
+                    span.loading-src.mastodon-accent {
+                        (PreEscaped(include_str!("../static/icons/mastodon.svg")))
+                        "mastodon"
+                    }
+                    span.loading-sep { (SEP) }
+                    span.loading-src.bluesky-accent {
+                        (PreEscaped(include_str!("../static/icons/bluesky.svg")))
+                        "bluesky"
+                    }
+                    span.loading-sep { (SEP) }
+                    span.loading-src.se-accent {
+                        (PreEscaped(include_str!("../static/icons/stackexchange.svg")))
+                        "stack exchange"
+                    }
+                    span.loading-sep { (SEP) }
+                    span.loading-src.discourse-accent {
+                        (PreEscaped(include_str!("../static/icons/discourse.svg")))
+                        "discourse"
+                    }
+                    span.loading-sep { (SEP) }
+                    span.loading-src.arxiv-accent {
+                        (PreEscaped(include_str!("../static/icons/arxiv.svg")))
+                        "arxiv"
+                    }
+                    span.loading-sep { (SEP) }
+                    span.loading-src.hf-accent {
+                        (PreEscaped(include_str!("../static/icons/huggingface.svg")))
+                        "hugging face"
+                    }
+                    span.loading-sep { (SEP) }
+                    span.loading-src.ph-accent {
+                        (PreEscaped(include_str!("../static/icons/producthunt.svg")))
+                        "product hunt"
+                    }
+                    span.loading-sep { (SEP) }
+                    span.loading-src.advisories-accent {
+                        (PreEscaped(include_str!("../static/icons/advisories.svg")))
+                        "advisories"
+                    }
+                    span.loading-sep { (SEP) }
+                    span.loading-src.crates-accent {
+                        (PreEscaped(include_str!("../static/icons/crates.svg")))
+                        "crates.io"
+                    }
+                    span.loading-sep { (SEP) }
+                    span.loading-src.feed-accent {
+                        (PreEscaped(include_str!("../static/icons/feed.svg")))
+                        "feeds"
+                    }
+                    @if !config::COMMAND_PANELS.is_empty() {
+                        span.loading-sep { (SEP) }
+                        span.loading-src.command-accent {
+                            (PreEscaped(include_str!("../static/icons/command.svg")))
+                            "commands"
+                        }
+                    }
                 }
             }
         },
@@ -1,23 +1,27 @@
 use std::time::Duration;
 
 use axum::Router;
-use axum::body::Body;
+use axum::body::{Body, Bytes};
 use axum::error_handling::HandleErrorLayer;
-use axum::extract::{Request, State};
+use axum::extract::{Path, Request, State};
 use axum::http::header::{
-    CACHE_CONTROL, CONTENT_ENCODING, CONTENT_TYPE, ETAG, HeaderName, HeaderValue, IF_NONE_MATCH,
-    REFERRER_POLICY, REFRESH, VARY, X_CONTENT_TYPE_OPTIONS, X_FRAME_OPTIONS,
+    AUTHORIZATION, CACHE_CONTROL, CONTENT_ENCODING, CONTENT_TYPE, ETAG, HeaderName, HeaderValue,
+    IF_NONE_MATCH, REFERRER_POLICY, REFRESH, VARY, X_CONTENT_TYPE_OPTIONS, X_FRAME_OPTIONS,
 };
 use axum::http::{HeaderMap, StatusCode};
 use axum::middleware::{self, Next};
-use axum::response::Response;
-use axum::routing::get;
+use axum::response::{IntoResponse, Response};
+use axum::routing::{get, post};
 use tower::ServiceBuilder;
 use tower::timeout::TimeoutLayer;
 use tower_http::compression::CompressionLayer;
 
-use crate::cache::AppState;
+use chrono::Utc;
+
+use crate::cache::{self, AppState};
 use crate::config;
+use crate::providers::ingest::{self, IngestRequest};
+use crate::worker;
 
 // ── Router ──────────────────────────────────────────────────────────
 
@@ -26,6 +30,8 @@ pub fn router(state: AppState) -> Router {
         .route("/", get(index))
         .route("/settings", get(settings))
         .route("/api/health", get(api_health))
+        .route("/api/data", get(api_data))
+        .route("/ingest/{panel}", post(ingest))
         .route("/favicon.svg", get(favicon))
         .route("/icon.svg", get(app_icon))
         .route("/manifest.json", get(manifest))
@@ -126,6 +132,103 @@ async fn api_health(State(state): State<AppState>) -> (StatusCode, &'static str)
     }
 }
 
+/// The token is checked before the body is parsed, so unauthenticated callers
+/// learn nothing about the schema.
+async fn ingest(
+    State(state): State<AppState>,
+    Path(panel): Path<String>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> (StatusCode, String) {
+    let Some(&(_, token_var)) = config::INGEST_PANELS.iter().find(|&&(p, _)| p == panel) else {
+        return (StatusCode::NOT_FOUND, "unknown panel".to_string());
+    };
+    let authorized = config::ingest_token(token_var).is_some_and(|t| bearer_matches(&headers, &t));
+    if !authorized {
+        return (StatusCode::UNAUTHORIZED, "invalid token".to_string());
+    }
+    // The next pull would overwrite the push with the upstream's items.
+    if config::mirror_upstream().is_some() {
+        return (
+            StatusCode::CONFLICT,
+            "this instance is a mirror, push to its upstream".to_string(),
+        );
+    }
+
+    let request: IngestRequest = match serde_json::from_slice(&body) {
+        Ok(request) => request,
+        Err(e) => return (StatusCode::BAD_REQUEST, format!("invalid JSON: {e}")),
+    };
+    tracing::info!(panel, items = request.items.len(), "ingest: items pushed");
+
+    let now = Utc::now().timestamp() as u64;
+    let data = state.data.load();
+    data.ingest_feed
+        .rcu(|feed| ingest::upsert(feed, &panel, request.items.clone(), now));
+    // Same guard as the HTML refresher: keep the loading page until the first scrape.
+    if data.last_fetched.timestamp() > 0 {
+        worker::rebuild_html(&state.data, &state.html).await;
+    }
+    (StatusCode::OK, "ok".to_string())
+}
+
+/// The whole snapshot, for mirrors. Disabled without `MIRROR_TOKEN`; while
+/// still loading, mirrors are told to wait rather than handed empty data.
+async fn api_data(State(state): State<AppState>, headers: HeaderMap) -> Response {
+    let Some(token) = config::mirror_token() else {
+        return StatusCode::NOT_FOUND.into_response();
+    };
+    if !bearer_matches(&headers, &token) {
+        return (StatusCode::UNAUTHORIZED, "invalid token").into_response();
+    }
+    let snapshot = state.data.load_full();
+    if snapshot.last_fetched.timestamp() == 0 {
+        return (StatusCode::SERVICE_UNAVAILABLE, "loading").into_response();
+    }
+
+    // spawn_blocking: serializing a full snapshot is CPU-bound.
+    let body = match tokio::task::spawn_blocking(move || serde_json::to_vec(&*snapshot)).await {
+        Ok(Ok(body)) => body,
+        Ok(Err(e)) => {
+            tracing::error!(error = %e, "failed to serialize snapshot");
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+        Err(e) => {
+            tracing::error!(error = %e, "snapshot serialization task panicked");
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    };
+    let etag = cache::compute_etag(&body);
+    let cache_control = "private, no-cache";
+    if let Some(r) = not_modified(&headers, &etag, cache_control) {
+        return r;
+    }
+    Response::builder()
+        .header(ETAG, &etag)
+        .header(CACHE_CONTROL, cache_control)
+        .header(CONTENT_TYPE, "application/json")
+        .body(Body::from(body))
+        .expect("valid response")
+}
+
+fn bearer_matches(headers: &HeaderMap, expected: &str) -> bool {
+    headers
+        .get(AUTHORIZATION)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix("Bearer "))
+        .is_some_and(|given| tokens_match(given, expected))
+}
+
+/// Constant-time, so response timing doesn't reveal how much of a guess matched.
+fn tokens_match(given: &str, expected: &str) -> bool {
+    given.len() == expected.len()
+        && given
+            .bytes()
+            .zip(expected.bytes())
+            .fold(0, |acc, (a, b)| acc | (a ^ b))
+            == 0
+}
+
 // ── Static assets ───────────────────────────────────────────────────
 
 async fn favicon() -> Response {
@@ -1,5 +1,26 @@
 (() => {
-	const SELECTORS = ["hn-select", "lang-select", "subreddit-select"];
+	const SELECTORS = [
+		"hn-select",
+		"jobs-workplace-select",
+		"jobs-location-select",
+		"jobs-tech-select",
+		"gh-view-select",
+		"lang-select",
+		"releases-select",
+		"forge-select",
+		"reddit-sort-select",
+		"subreddit-select",
+		"lemmy-select",
+		"tildes-select",
+		"mastodon-select",
+		"bluesky-select",
+		"se-select",
+		"discourse-select",
+		"arxiv-select",
+		"ph-select",
+		"advisories-select",
+	];
+	const TAB_GROUPS = ["gh", "forge", "se", "discourse", "hf", "ph", "crates"];
 
 	function scoreOf(el, sel) {
 		return (
@@ -25,35 +46,83 @@
 			ol.style.display = ol.dataset[attr] === value ? "block" : "none";
 	}
 
+	// Syncs a radio tab group (`{prefix}-tab-{tab}` → `#{prefix}-{tab}`) within
+	// its panel and returns the selected tab name.
+	function applyTabs(prefix) {
+		const radio = $(`[name="${prefix}-tab"]:checked`);
+		if (!radio) return null;
+		const tab = radio.id.replace(`${prefix}-tab-`, "");
+		const panel = radio.closest(".panel");
+		for (const el of panel.querySelectorAll(".tab-content"))
+			el.classList.toggle("active", el.id === `${prefix}-${tab}`);
+		for (const label of panel.querySelectorAll(".tab-labels label")) {
+			const active = label.getAttribute("for") === radio.id;
+			label.classList.toggle("active", active);
+			label.setAttribute("aria-selected", active);
+		}
+		return tab;
+	}
+
 	function applyFilters() {
-		const page = $(".hn-select").value;
-		showList(".hn-panel ol.stories", "forPage", page);
+		const hnSelect = $(".hn-select");
+		showList(".hn-panel ol.stories", "forPage", hnSelect.value);
 		const hnLink = $(".hn-link");
-		if (hnLink) {
-			const hnPath = page === "top" ? "" : `/${page}`;
-			hnLink.href = `https://news.ycombinator.com${hnPath}`;
+		const hnHref = hnSelect.selectedOptions[0]?.dataset.href;
+		if (hnLink && hnHref) hnLink.href = hnHref;
+
+		const workplace = $(".jobs-workplace-select").value;
+		const region = $(".jobs-location-select").value;
+		const tech = $(".jobs-tech-select").value;
+		for (const li of $$(".jobs-panel li.job")) {
+			const d = li.dataset;
+			li.style.display =
+				(workplace === "all" || d.workplace === workplace) &&
+				(region === "all" || d.regions.split("|").includes(region)) &&
+				(tech === "all" || d.tech.split("|").includes(tech))
+					? ""
+					: "none";
 		}
 
-		const tabId = $('[name="gh-tab"]:checked').id;
-		const period = tabId.replace("gh-tab-", "");
-		for (const el of $$(".tab-content"))
-			el.classList.toggle("active", el.id === `gh-${period}`);
-		for (const label of $$(".tab-labels label")) {
-			const active = label.getAttribute("for") === tabId;
-			label.classList.toggle("active", active);
-			label.setAttribute("aria-selected", active);
-		}
+		const period = applyTabs("gh");
 
 		const lang = $(".lang-select").value;
-		showList(".gh-panel ol.repos", "forLang", lang);
+		const view = $(".gh-view-select").value;
+		const devs = view === "developers";
+		const releases = view === "releases";
+		const repo = $(".releases-select").value;
+		// Releases have no period or language: swap those controls for the repo select.
+		for (const sel of [".gh-panel .tab-labels", ".gh-tabs", ".lang-select"])
+			$(sel).style.display = releases ? "none" : "";
+		$(".releases-select").style.display = releases ? "" : "none";
+		showList(".gh-panel ol.repos", "forLang", view === "repos" ? lang : null);
+		showList(".gh-panel ol.gh-devs", "forLang", devs ? lang : null);
+		showList(".gh-panel ol.releases", "forRepo", releases ? repo : null);
 		const ghLink = $(".gh-link");
 		if (ghLink) {
+			const viewPath = devs ? "/developers" : "";
 			const langPath = lang === "all" || lang === "mine" ? "" : `/${lang}`;
-			ghLink.href = `https://github.com/trending${langPath}?since=${period}`;
+			ghLink.href = !releases
+				? `https://github.com/trending${viewPath}${langPath}?since=${period}`
+				: repo === "all"
+					? "https://github.com/trending"
+					: `https://github.com/${repo}/releases`;
 		}
 
-		const sub = $(".subreddit-select").value;
-		showList(".reddit-panel ol.reddit-posts", "forSub", sub);
+		applyTabs("forge");
+		showList(".forge-panel ol.repos", "forSource", $(".forge-select").value);
+
+		const sort = $(".reddit-sort-select").value;
+		const subSelect = $(".subreddit-select");
+		const sub = subSelect.value;
+		for (const ol of $$(".reddit-panel ol.reddit-posts"))
+			ol.style.display =
+				ol.dataset.forSub === sub && ol.dataset.forSort === sort
+					? "block"
+					: "none";
+		// Subreddits are only fetched in their configured sorts.
+		for (const o of subSelect.options)
+			o.disabled =
+				o.value !== "all" && !o.dataset.sorts.split(" ").includes(sort);
 		const redditLink = $(".reddit-link");
 		if (redditLink) {
 			redditLink.href =
@@ -61,6 +130,65 @@
 					? "https://www.reddit.com"
 					: `https://www.reddit.com/r/${sub}`;
 		}
+
+		showList(
+			".lemmy-panel ol.lemmy-posts",
+			"forCommunity",
+			$(".lemmy-select").value,
+		);
+
+		showList(
+			".tildes-panel ol.tildes-topics",
+			"forGroup",
+			$(".tildes-select").value,
+		);
+
+		showList(
+			".mastodon-panel ol.mastodon-posts",
+			"forSource",
+			$(".mastodon-select").value,
+		);
+
+		showList(
+			".bluesky-panel ol.bluesky-posts",
+			"forSource",
+			$(".bluesky-select").value,
+		);
+
+		applyTabs("se");
+		showList(".se-panel ol.se-questions", "forSite", $(".se-select").value);
+
+		applyTabs("discourse");
+		showList(
+			".discourse-panel ol.discourse-topics",
+			"forForum",
+			$(".discourse-select").value,
+		);
+
+		showList(
+			".arxiv-panel ol.arxiv-papers",
+			"forCategory",
+			$(".arxiv-select").value,
+		);
+
+		applyTabs("hf");
+
+		applyTabs("ph");
+		showList(".ph-panel ol.ph-launches", "forTopic", $(".ph-select").value);
+
+		showList(
+			".advisories-panel ol.advisories",
+			"forEcosystem",
+			$(".advisories-select").value,
+		);
+
+		applyTabs("crates");
+
+		for (const panel of $$(".feed-panel")) {
+			const feed = panel.querySelector(".feed-select").value;
+			for (const ol of panel.querySelectorAll("ol.feed-items"))
+				ol.style.display = ol.dataset.forFeed === feed ? "block" : "none";
+		}
 	}
 
 	function applyProfile() {
@@ -69,26 +197,24 @@
 
 		const hnSelect = $(".hn-select");
 		if (hnSelect && d["hn-select"]) hnSelect.value = d["hn-select"];
-		const ghRadio = d.gh && document.getElementById(d.gh);
-		if (ghRadio) ghRadio.checked = true;
+		for (const group of TAB_GROUPS) {
+			const radio = d[group] && document.getElementById(d[group]);
+			if (radio) radio.checked = true;
+		}
 
 		const dashboard = $(".dashboard");
-		const PANELS = [
-			["hn", ".hn-panel", ".dot-hn"],
-			["gh", ".gh-panel", ".dot-gh"],
-			["reddit", ".reddit-panel", ".dot-reddit"],
-		];
-		const panelOrder = order || DEFAULT_ORDER;
+		const panelOrder = order || [];
 		let visibleCount = 0;
-		for (const [key, panelSel, dotSel] of PANELS) {
+		// Panels missing from a saved order (e.g. configured after it was saved)
+		// keep their page position, after the ordered ones.
+		for (const [i, panel] of $$(".panel").entries()) {
+			const key = panel.dataset.panel;
 			const show = !panels || panels.includes(key);
-			const idx = panelOrder.indexOf(key);
-			const panel = $(panelSel);
-			const dot = $(dotSel);
-			if (panel) {
-				panel.classList.toggle("panel-hidden", !show);
-				panel.style.order = idx;
-			}
+			const pos = panelOrder.indexOf(key);
+			const idx = pos === -1 ? panelOrder.length + i : pos;
+			const dot = $(`.swipe-dot[data-panel="${key}"]`);
+			panel.classList.toggle("panel-hidden", !show);
+			panel.style.order = idx;
 			if (dot) {
 				dot.classList.toggle("dot-hidden", !show);
 				dot.style.order = idx;
@@ -107,11 +233,11 @@
 				subSelect.value = "all";
 				subSelect.dispatchEvent(new Event("change", { bubbles: true }));
 			}
-			const allList = $('.reddit-posts[data-for-sub="all"]');
-			if (allList) {
+			for (const allList of $$('.reddit-posts[data-for-sub="all"]')) {
+				const sort = allList.dataset.forSort;
 				allList.replaceChildren(
 					...mergeClones(
-						'.reddit-posts[data-for-sub="$"]',
+						`.reddit-posts[data-for-sub="$"][data-for-sort="${sort}"]`,
 						".reddit-post",
 						".reddit-score",
 						subs,
@@ -131,24 +257,29 @@
 					langSelect.options[0].after(opt);
 				}
 				const lowerLangs = langs.map((l) => l.toLowerCase());
-				for (const tab of $$(".tab-content")) {
-					tab.querySelector('.repos[data-for-lang="mine"]')?.remove();
-					const ol = document.createElement("ol");
-					ol.className = "repos";
-					ol.dataset.forLang = "mine";
-					ol.style.display = "none";
-					const repos = [];
-					for (const lang of lowerLangs) {
-						const src = tab.querySelector(`.repos[data-for-lang="${lang}"]`);
-						if (src)
-							for (const r of src.querySelectorAll(".repo"))
-								repos.push(r.cloneNode(true));
+				for (const tab of $$(".gh-panel .tab-content")) {
+					for (const cls of ["repos", "gh-devs"]) {
+						tab.querySelector(`.${cls}[data-for-lang="mine"]`)?.remove();
+						const ol = document.createElement("ol");
+						ol.className = cls;
+						ol.dataset.forLang = "mine";
+						ol.style.display = "none";
+						const repos = [];
+						for (const lang of lowerLangs) {
+							const src = tab.querySelector(
+								`.${cls}[data-for-lang="${lang}"]`,
+							);
+							if (src)
+								for (const r of src.querySelectorAll(".repo"))
+									repos.push(r.cloneNode(true));
+						}
+						// Developers carry no stars; the stable sort keeps page order.
+						repos.sort(
+							(a, b) => scoreOf(b, ".repo-stars") - scoreOf(a, ".repo-stars"),
+						);
+						ol.append(...repos);
+						tab.appendChild(ol);
 					}
-					repos.sort(
-						(a, b) => scoreOf(b, ".repo-stars") - scoreOf(a, ".repo-stars"),
-					);
-					ol.append(...repos);
-					tab.appendChild(ol);
 				}
 			} else {
 				if (hasMine) {
@@ -158,7 +289,7 @@
 					}
 					hasMine.remove();
 				}
-				for (const ol of $$('.repos[data-for-lang="mine"]')) ol.remove();
+				for (const ol of $$('.gh-panel ol[data-for-lang="mine"]')) ol.remove();
 			}
 		}
 	}
@@ -183,6 +314,10 @@
 		const el = $(`.${c}`);
 		if (el && state[c]) el.value = state[c];
 	}
+	for (const panel of $$(".feed-panel")) {
+		const saved = state[`feed-select-${panel.dataset.panel}`];
+		if (saved) panel.querySelector(".feed-select").value = saved;
+	}
 	applyProfile();
 	applyFilters();
 
@@ -193,7 +328,11 @@
 		const panel = $$(".panel")[panelIdx];
 		if (!panel) return [];
 		for (const ol of panel.querySelectorAll("ol")) {
-			if (ol.style.display !== "none") return ol.querySelectorAll("li");
+			// Items can be hidden individually (jobs filters), not just whole lists.
+			if (ol.style.display !== "none")
+				return [...ol.querySelectorAll("li")].filter(
+					(li) => li.style.display !== "none",
+				);
 		}
 		return [];
 	}
@@ -240,12 +379,17 @@
 
 	document.addEventListener("change", () => {
 		update((d) => {
-			const r = $('[name="gh-tab"]:checked');
-			if (r) d.gh = r.id;
+			for (const group of TAB_GROUPS) {
+				const r = $(`[name="${group}-tab"]:checked`);
+				if (r) d[group] = r.id;
+			}
 			for (const c of SELECTORS) {
 				const el = $(`.${c}`);
 				if (el) d[c] = el.value;
 			}
+			for (const panel of $$(".feed-panel"))
+				d[`feed-select-${panel.dataset.panel}`] =
+					panel.querySelector(".feed-select").value;
 		});
 		applyFilters();
 		clearFocus();
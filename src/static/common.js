@@ -1,5 +1,4 @@
 const KEY = "tty1";
-const DEFAULT_ORDER = ["hn", "gh", "reddit"];
 
 function load() {
 	try {
@@ -85,7 +85,7 @@
 		if (s.length < checkGroups.sub.length) params.set("subs", s.join(","));
 		if (l.length < checkGroups.lang.length) params.set("langs", l.join(","));
 		const o = panelOrder();
-		if (!o.every((k, i) => k === DEFAULT_ORDER[i]))
+		if (!o.every((k, i) => k === defaultOrder[i]))
 			params.set("order", o.join(","));
 		const hn = activeVal(hnBtns, "hn");
 		if (hn !== hnBtns[0]?.dataset.hn) params.set("hn", hn);
@@ -107,7 +107,7 @@
 			else d[key] = values;
 		}
 		const order = panelOrder();
-		if (order.every((k, i) => k === DEFAULT_ORDER[i])) delete d.order;
+		if (order.every((k, i) => k === defaultOrder[i])) delete d.order;
 		else d.order = order;
 		const hn = activeVal(hnBtns, "hn");
 		if (hn !== hnBtns[0]?.dataset.hn) d["hn-select"] = hn;
@@ -119,6 +119,8 @@
 		if (shareInput) shareInput.value = generateShareUrl();
 	}
 
+	// The server renders toggles in default order; capture it before applying a saved one.
+	const defaultOrder = panelOrder();
 	const d = load();
 	if (d.panels)
 		for (const b of panelBtns)
@@ -221,7 +223,7 @@
 			for (const c of checks) c.checked = true;
 		updateToggleText("sub");
 		updateToggleText("lang");
-		reorderButtons(DEFAULT_ORDER);
+		reorderButtons(defaultOrder);
 		if (shareInput) shareInput.value = generateShareUrl();
 	});
 })();
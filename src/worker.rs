@@ -6,7 +6,11 @@ use chrono::Utc;
 use crate::cache::{DataSnapshot, HtmlSnapshot, SharedData, SharedHtml};
 use crate::client::Client;
 use crate::config;
-use crate::providers::{github, hackernews, reddit};
+use crate::providers::{
+    advisories, arxiv, bluesky, command, crates, discourse, feeds, forgejo, github, hackernews,
+    hiring, huggingface, lemmy, mastodon, mirror, producthunt, reddit, releases, stackexchange,
+    status, tildes,
+};
 
 /// Each provider fails independently — a single provider outage never blocks the others.
 pub async fn run_scraper(
@@ -17,14 +21,7 @@ pub async fn run_scraper(
     story_count: usize,
 ) {
     loop {
-        let (hn, gh, reddit, elapsed) = fetch_and_update(&data, &client, story_count).await;
-        tracing::info!(
-            hn,
-            gh,
-            reddit,
-            elapsed_secs = elapsed.as_secs(),
-            "data cache updated"
-        );
+        fetch_and_update(&data, &client, story_count).await;
         rebuild_html(&data, &html).await;
         let jitter = Duration::from_secs(fastrand::u64(0..=config::SCRAPE_JITTER_SECS * 2));
         let sleep_dur = interval - Duration::from_secs(config::SCRAPE_JITTER_SECS) + jitter;
@@ -33,6 +30,39 @@ pub async fn run_scraper(
     }
 }
 
+/// Runs instead of the scraper on a mirror: no provider is touched, the
+/// upstream's snapshot is stored as is. Its `last_fetched` comes along, so the
+/// footer shows when the data was actually scraped.
+pub async fn run_mirror(
+    data: SharedData,
+    html: SharedHtml,
+    client: Client,
+    upstream: String,
+    interval: Duration,
+) {
+    let url = format!("{}/api/data", upstream.trim_end_matches('/'));
+    let token = config::mirror_token();
+    if token.is_none() {
+        tracing::warn!("MIRROR_TOKEN is unset, the upstream will refuse to share its data");
+    }
+    let mut etag = None;
+    loop {
+        match mirror::fetch_snapshot(&client, &url, token.as_deref(), etag.as_deref()).await {
+            Ok(Some(mirrored)) => {
+                tracing::info!(upstream = url, "mirrored snapshot updated");
+                data.store(Arc::new(mirrored.snapshot));
+                etag = mirrored.etag;
+                rebuild_html(&data, &html).await;
+            }
+            Ok(None) => tracing::debug!(upstream = url, "mirrored snapshot unchanged"),
+            Err(e) => {
+                tracing::warn!(upstream = url, error = %e, "failed to mirror, keeping previous")
+            }
+        }
+        tokio::time::sleep(interval).await;
+    }
+}
+
 /// Separate from scraper: re-renders HTML every minute so relative timestamps ("3h ago")
 /// stay fresh between the 30-minute data fetches.
 pub async fn run_html_refresher(data: SharedData, html: SharedHtml, interval: Duration) {
@@ -44,7 +74,7 @@ pub async fn run_html_refresher(data: SharedData, html: SharedHtml, interval: Du
     }
 }
 
-async fn rebuild_html(data: &SharedData, html: &SharedHtml) {
+pub async fn rebuild_html(data: &SharedData, html: &SharedHtml) {
     let snap = data.load_full();
     let start = Instant::now();
     // spawn_blocking: Maud rendering is CPU-bound and would block the async runtime.
@@ -77,40 +107,162 @@ where
     }
 }
 
-async fn fetch_and_update(
-    data: &SharedData,
-    client: &Client,
-    story_count: usize,
-) -> (usize, usize, usize, Duration) {
+async fn fetch_and_update(data: &SharedData, client: &Client, story_count: usize) {
     tracing::info!("scrape cycle starting");
 
     let start = Instant::now();
+    let current = data.load_full();
 
-    // All three providers fetch concurrently — each handles its own rate limiting internally.
-    let (hn_pages, gh_trending, reddit_feed) = tokio::join!(
+    // All providers fetch concurrently — each handles its own rate limiting internally.
+    let (
+        hn_pages,
+        status_feed,
+        hiring_feed,
+        (gh_trending, gh_developers),
+        gh_releases,
+        forge_repos,
+        reddit_feed,
+        lemmy_feed,
+        tildes_feed,
+        mastodon_feed,
+        bluesky_feed,
+        stackexchange_feed,
+        discourse_feed,
+        arxiv_feed,
+        hf_trending,
+        producthunt_feed,
+        advisory_feed,
+        crates_feed,
+        feeds,
+        command_feed,
+    ) = tokio::join!(
         hackernews::fetch_all_pages(client, story_count),
+        status::fetch_status(client),
+        hiring::fetch_hiring(client),
         github::fetch_all_trending(client),
+        releases::fetch_releases(client),
+        forgejo::fetch_forge_repos(client),
         reddit::fetch_reddit_feed(client),
+        lemmy::fetch_lemmy_feed(client),
+        tildes::fetch_tildes_feed(client),
+        mastodon::fetch_mastodon_feed(client),
+        bluesky::fetch_bluesky_feed(client),
+        stackexchange::fetch_stackexchange_feed(client),
+        discourse::fetch_discourse_feed(client),
+        arxiv::fetch_arxiv_feed(client),
+        huggingface::fetch_trending(client),
+        producthunt::fetch_producthunt_feed(client),
+        advisories::fetch_advisories(client),
+        crates::fetch_crates_feed(client),
+        feeds::fetch_all_feeds(client, &current.feeds),
+        command::run_all(&current.command_feed),
     );
 
-    let current = data.load();
-
     let hn_pages = keep_if_empty(hn_pages, &current.hn_pages, "HN");
+    let hiring_feed = keep_if_empty(hiring_feed, &current.hiring_feed, "HN hiring");
     let gh_trending = keep_if_empty(gh_trending, &current.gh_trending, "GitHub trending");
+    let gh_developers = keep_if_empty(
+        gh_developers,
+        &current.gh_developers,
+        "GitHub trending developers",
+    );
+    let gh_releases = keep_if_empty(gh_releases, &current.gh_releases, "GitHub releases");
+    let forge_repos = keep_if_empty(forge_repos, &current.forge_repos, "Forgejo");
     let reddit_feed = keep_if_empty(reddit_feed, &current.reddit_feed, "Reddit");
+    let lemmy_feed = keep_if_empty(lemmy_feed, &current.lemmy_feed, "Lemmy");
+    let tildes_feed = keep_if_empty(tildes_feed, &current.tildes_feed, "Tildes");
+    let mastodon_feed = keep_if_empty(mastodon_feed, &current.mastodon_feed, "Mastodon");
+    let bluesky_feed = keep_if_empty(bluesky_feed, &current.bluesky_feed, "Bluesky");
+    let stackexchange_feed = keep_if_empty(
+        stackexchange_feed,
+        &current.stackexchange_feed,
+        "Stack Exchange",
+    );
+    let discourse_feed = keep_if_empty(discourse_feed, &current.discourse_feed, "Discourse");
+    let arxiv_feed = keep_if_empty(arxiv_feed, &current.arxiv_feed, "arXiv");
+    let hf_trending = keep_if_empty(hf_trending, &current.hf_trending, "Hugging Face");
+    let producthunt_feed =
+        keep_if_empty(producthunt_feed, &current.producthunt_feed, "Product Hunt");
+    let advisory_feed = keep_if_empty(advisory_feed, &current.advisory_feed, "advisories");
+    let crates_feed = keep_if_empty(crates_feed, &current.crates_feed, "crates.io");
+    let feeds = keep_if_empty(feeds, &current.feeds, "Feeds");
 
     let hn_count: usize = hn_pages.values().map(|v| v.len()).sum();
+    let degraded = status_feed
+        .iter()
+        .filter(|s| s.indicator.is_degraded())
+        .count();
+    let hiring_count: usize = hiring_feed.values().map(|v| v.len()).sum();
     let gh_count: usize = gh_trending.values().map(|v| v.len()).sum();
+    let gh_dev_count: usize = gh_developers.values().map(|v| v.len()).sum();
+    let gh_release_count: usize = gh_releases.values().map(|v| v.len()).sum();
+    let forge_count: usize = forge_repos.values().map(|v| v.len()).sum();
     let reddit_count: usize = reddit_feed.values().map(|v| v.len()).sum();
+    let lemmy_count: usize = lemmy_feed.values().map(|v| v.len()).sum();
+    let tildes_count: usize = tildes_feed.values().map(|v| v.len()).sum();
+    let mastodon_count: usize = mastodon_feed.values().map(|v| v.len()).sum();
+    let bluesky_count: usize = bluesky_feed.values().map(|v| v.len()).sum();
+    let stackexchange_count: usize = stackexchange_feed.values().map(|v| v.len()).sum();
+    let discourse_count: usize = discourse_feed.values().map(|v| v.len()).sum();
+    let arxiv_count: usize = arxiv_feed.values().map(|v| v.len()).sum();
+    let hf_count: usize = hf_trending.values().map(|v| v.len()).sum();
+    let producthunt_count: usize = producthunt_feed.values().map(|v| v.len()).sum();
+    let advisory_count: usize = advisory_feed.values().map(|v| v.len()).sum();
+    let crates_count: usize = crates_feed.values().map(|v| v.len()).sum();
+    let feed_count: usize = feeds.values().map(|v| v.len()).sum();
+    let command_count: usize = command_feed.values().map(|p| p.items.len()).sum();
 
     let last_fetched = Utc::now();
 
     data.store(Arc::new(DataSnapshot {
         hn_pages,
+        status_feed,
+        hiring_feed,
         gh_trending,
+        gh_developers,
+        gh_releases,
+        forge_repos,
         reddit_feed,
+        lemmy_feed,
+        tildes_feed,
+        mastodon_feed,
+        bluesky_feed,
+        stackexchange_feed,
+        discourse_feed,
+        arxiv_feed,
+        hf_trending,
+        producthunt_feed,
+        advisory_feed,
+        crates_feed,
+        feeds,
+        command_feed,
+        ingest_feed: current.ingest_feed.clone(),
         last_fetched,
     }));
 
-    (hn_count, gh_count, reddit_count, start.elapsed())
+    tracing::info!(
+        hn = hn_count,
+        degraded,
+        hiring = hiring_count,
+        gh = gh_count,
+        gh_developers = gh_dev_count,
+        gh_releases = gh_release_count,
+        forgejo = forge_count,
+        reddit = reddit_count,
+        lemmy = lemmy_count,
+        tildes = tildes_count,
+        mastodon = mastodon_count,
+        bluesky = bluesky_count,
+        stackexchange = stackexchange_count,
+        discourse = discourse_count,
+        arxiv = arxiv_count,
+        huggingface = hf_count,
+        producthunt = producthunt_count,
+        advisories = advisory_count,
+        crates = crates_count,
+        feeds = feed_count,
+        commands = command_count,
+        elapsed_secs = start.elapsed().as_secs(),
+        "data cache updated"
+    );
 }